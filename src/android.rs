@@ -0,0 +1,195 @@
+//! Contains exporters of polygons and morphs to Android drawables.
+//!
+//! Polygons are exported as `VectorDrawable` XML and morphs as
+//! `AnimatedVectorDrawable` XML animating `pathData`. Android can only morph
+//! paths with the same commands at both ends, which is the case for the
+//! matched cubics of a [`Morph`], so every cubic is written as a `C` command,
+//! straight ones included.
+
+use crate::{
+    Cubic, Fit, Morph, RoundedPolygon,
+    geometry::{Aabb, Point, Size},
+};
+
+/// The name of the exported path, targeted by the animation.
+const PATH_NAME: &str = "shape";
+
+/// Returns `value` with at most 3 decimal places and without trailing zeros.
+fn number(value: f32) -> String {
+    let text = format!("{value:.3}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+
+    if text == "-0" { "0".to_owned() } else { text.to_owned() }
+}
+
+fn point(point: Point) -> String {
+    format!("{},{}", number(point.x), number(point.y))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Returns the `pathData` of the closed outline made of `cubics`, with a `C`
+/// command for each cubic.
+pub fn path_data<I: IntoIterator<Item = Cubic>>(cubics: I) -> String {
+    let mut cubics = cubics.into_iter().peekable();
+    let Some(start) = cubics.peek().map(Cubic::anchor0) else {
+        return String::new();
+    };
+
+    let commands = cubics
+        .map(|cubic| format!("C{} {} {}", point(cubic.control0()), point(cubic.control1()), point(cubic.anchor1())))
+        .collect::<Vec<_>>();
+
+    format!("M{} {} Z", point(start), commands.join(" "))
+}
+
+/// Options of exported drawables.
+///
+/// ```
+/// use polymorpher::{Morph, android::VectorDrawable, shapes};
+///
+/// let morph = Morph::new(shapes::circle(None), shapes::cookie7());
+/// let xml = VectorDrawable::new()
+///     .with_fill_color(0xFF6750A4)
+///     .with_duration(500)
+///     .morph_xml(&morph);
+///
+/// assert!(xml.starts_with("<animated-vector"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorDrawable {
+    size: Size,
+    viewport: Size,
+    fit: Fit,
+    fill_color: u32,
+    duration: u32,
+    interpolator: String,
+}
+
+impl Default for VectorDrawable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VectorDrawable {
+    pub fn new() -> Self {
+        Self {
+            size: Size::splat(24.0),
+            viewport: Size::splat(24.0),
+            fit: Fit::default(),
+            fill_color: 0xFF00_0000,
+            duration: 300,
+            interpolator: "@android:interpolator/fast_out_slow_in".to_owned(),
+        }
+    }
+
+    /// Sets the intrinsic size of the drawable in dp.
+    #[must_use]
+    pub const fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+
+        self
+    }
+
+    /// Sets the size of the viewport, which the shape is fitted into.
+    #[must_use]
+    pub const fn with_viewport(mut self, viewport: Size) -> Self {
+        self.viewport = viewport;
+
+        self
+    }
+
+    /// Sets how the shape is fitted into the viewport.
+    #[must_use]
+    pub const fn with_fit(mut self, fit: Fit) -> Self {
+        self.fit = fit;
+
+        self
+    }
+
+    /// Sets the fill color as `0xAARRGGBB`.
+    #[must_use]
+    pub const fn with_fill_color(mut self, color: u32) -> Self {
+        self.fill_color = color;
+
+        self
+    }
+
+    /// Sets the duration of the morph animation in milliseconds.
+    #[must_use]
+    pub const fn with_duration(mut self, duration: u32) -> Self {
+        self.duration = duration;
+
+        self
+    }
+
+    /// Sets the interpolator of the morph animation, e.g.
+    /// `@android:anim/linear_interpolator`.
+    #[must_use]
+    pub fn with_interpolator(mut self, interpolator: impl Into<String>) -> Self {
+        self.interpolator = interpolator.into();
+
+        self
+    }
+
+    fn viewport_rect(&self) -> Aabb {
+        Aabb::new(Point::zero(), self.viewport.to_vector().to_point())
+    }
+
+    fn vector(&self, path_data: &str, indent: &str) -> String {
+        [
+            format!(r#"{indent}<vector xmlns:android="http://schemas.android.com/apk/res/android""#),
+            format!(r#"{indent}    android:width="{}dp""#, number(self.size.width)),
+            format!(r#"{indent}    android:height="{}dp""#, number(self.size.height)),
+            format!(r#"{indent}    android:viewportWidth="{}""#, number(self.viewport.width)),
+            format!(r#"{indent}    android:viewportHeight="{}">"#, number(self.viewport.height)),
+            format!("{indent}    <path"),
+            format!(r#"{indent}        android:name="{PATH_NAME}""#),
+            format!(r##"{indent}        android:fillColor="#{:08X}""##, self.fill_color),
+            format!(r#"{indent}        android:pathData="{path_data}" />"#),
+            format!("{indent}</vector>"),
+        ]
+        .join("\n")
+    }
+
+    /// Returns the `VectorDrawable` XML of the `polygon` fitted into the
+    /// viewport.
+    pub fn polygon_xml(&self, polygon: &RoundedPolygon) -> String {
+        let polygon = polygon.clone().fit_into(self.viewport_rect(), self.fit);
+
+        format!("{}\n", self.vector(&path_data(polygon.cubics), ""))
+    }
+
+    /// Returns the `AnimatedVectorDrawable` XML animating the `morph` from the
+    /// start to the end polygon, with the whole transition fitted into the
+    /// viewport.
+    pub fn morph_xml(&self, morph: &Morph) -> String {
+        let morph = morph.clone().fit_into(self.viewport_rect(), self.fit);
+        let from = path_data(morph.as_cubics(0.0));
+        let to = path_data(morph.as_cubics(1.0));
+
+        [
+            r#"<animated-vector xmlns:android="http://schemas.android.com/apk/res/android""#.to_owned(),
+            r#"    xmlns:aapt="http://schemas.android.com/aapt">"#.to_owned(),
+            r#"    <aapt:attr name="android:drawable">"#.to_owned(),
+            self.vector(&from, "        "),
+            "    </aapt:attr>".to_owned(),
+            format!(r#"    <target android:name="{PATH_NAME}">"#),
+            r#"        <aapt:attr name="android:animation">"#.to_owned(),
+            "            <objectAnimator".to_owned(),
+            r#"                android:propertyName="pathData""#.to_owned(),
+            format!(r#"                android:duration="{}""#, self.duration),
+            format!(r#"                android:interpolator="{}""#, escape(&self.interpolator)),
+            format!(r#"                android:valueFrom="{from}""#),
+            format!(r#"                android:valueTo="{to}""#),
+            r#"                android:valueType="pathType" />"#.to_owned(),
+            "        </aapt:attr>".to_owned(),
+            "    </target>".to_owned(),
+            "</animated-vector>\n".to_owned(),
+        ]
+        .join("\n")
+    }
+}
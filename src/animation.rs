@@ -0,0 +1,260 @@
+//! Contains a spring-physics driver for animating the progress of a
+//! [`Morph`].
+
+use crate::{
+    Cubic, Morph,
+    path::{PathBuilder, add_cubics},
+};
+
+/// Describes the physical properties of a spring with a unit mass.
+///
+/// The predefined constants follow the Material 3 motion scheme tokens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// How strongly the spring pulls towards its target.
+    pub stiffness: f32,
+    /// How quickly oscillations decay. Values below `1.0` overshoot the target
+    /// (under-damped), `1.0` reaches it as fast as possible without
+    /// overshooting (critically damped), and values above `1.0` approach it
+    /// slowly (over-damped).
+    pub damping_ratio: f32,
+}
+
+impl Spring {
+    pub const DAMPING_RATIO_HIGH_BOUNCY: f32 = 0.2;
+    pub const DAMPING_RATIO_LOW_BOUNCY: f32 = 0.75;
+    pub const DAMPING_RATIO_MEDIUM_BOUNCY: f32 = 0.5;
+    pub const DAMPING_RATIO_NO_BOUNCY: f32 = 1.0;
+    pub const DEFAULT_EFFECTS: Self = Self::new(1600.0, 1.0);
+    pub const EXPRESSIVE_DEFAULT_SPATIAL: Self = Self::new(380.0, 0.8);
+    pub const EXPRESSIVE_FAST_SPATIAL: Self = Self::new(800.0, 0.6);
+    pub const EXPRESSIVE_SLOW_SPATIAL: Self = Self::new(200.0, 0.8);
+    pub const FAST_EFFECTS: Self = Self::new(3800.0, 1.0);
+    pub const SLOW_EFFECTS: Self = Self::new(800.0, 1.0);
+    pub const STANDARD_DEFAULT_SPATIAL: Self = Self::new(700.0, 0.9);
+    pub const STANDARD_FAST_SPATIAL: Self = Self::new(1400.0, 0.9);
+    pub const STANDARD_SLOW_SPATIAL: Self = Self::new(300.0, 0.9);
+    pub const STIFFNESS_HIGH: f32 = 10_000.0;
+    pub const STIFFNESS_LOW: f32 = 200.0;
+    pub const STIFFNESS_MEDIUM: f32 = 1500.0;
+    pub const STIFFNESS_MEDIUM_LOW: f32 = 400.0;
+    pub const STIFFNESS_VERY_LOW: f32 = 50.0;
+
+    pub const fn new(stiffness: f32, damping_ratio: f32) -> Self {
+        Self { stiffness, damping_ratio }
+    }
+
+    /// Returns the displacement from the target and the velocity of the spring
+    /// after `time` seconds, starting with the given `displacement` and
+    /// `velocity`.
+    ///
+    /// The closed-form solution of the damped harmonic oscillator is used, so
+    /// the result does not depend on how time is subdivided into steps.
+    pub fn evaluate(&self, displacement: f32, velocity: f32, time: f32) -> (f32, f32) {
+        let natural_frequency = self.stiffness.sqrt();
+        let damping_ratio = self.damping_ratio.max(0.0);
+
+        if (damping_ratio - 1.0).abs() < 1e-4 {
+            // Critically damped
+            let a = displacement;
+            let b = natural_frequency.mul_add(displacement, velocity);
+            let decay = (-natural_frequency * time).exp();
+
+            (decay * b.mul_add(time, a), decay * natural_frequency.mul_add(-b.mul_add(time, a), b))
+        } else if damping_ratio < 1.0 {
+            // Under-damped
+            let damped_frequency = natural_frequency * damping_ratio.mul_add(-damping_ratio, 1.0).sqrt();
+            let decay_rate = damping_ratio * natural_frequency;
+            let a = displacement;
+            let b = decay_rate.mul_add(displacement, velocity) / damped_frequency;
+            let decay = (-decay_rate * time).exp();
+            let (sin, cos) = (damped_frequency * time).sin_cos();
+
+            (
+                decay * a.mul_add(cos, b * sin),
+                decay
+                    * b.mul_add(damped_frequency, -decay_rate * a)
+                        .mul_add(cos, -a.mul_add(damped_frequency, decay_rate * b) * sin),
+            )
+        } else {
+            // Over-damped
+            let root = damping_ratio.mul_add(damping_ratio, -1.0).sqrt();
+            let r1 = -natural_frequency * (damping_ratio - root);
+            let r2 = -natural_frequency * (damping_ratio + root);
+            let c2 = r1.mul_add(displacement, -velocity) / (r1 - r2);
+            let c1 = displacement - c2;
+            let e1 = (r1 * time).exp();
+            let e2 = (r2 * time).exp();
+
+            (c1.mul_add(e1, c2 * e2), (c1 * r1).mul_add(e1, c2 * r2 * e2))
+        }
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::EXPRESSIVE_DEFAULT_SPATIAL
+    }
+}
+
+/// A value driven towards a target by a [`Spring`].
+///
+/// Changing the target while the animation is running keeps the current value
+/// and velocity, so interrupted gestures (e.g. press followed by an early
+/// release) continue smoothly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringAnimation {
+    spring: Spring,
+    value: f32,
+    velocity: f32,
+    target: f32,
+}
+
+impl SpringAnimation {
+    /// The distance from the target under which the animation may come to
+    /// rest.
+    pub const DISPLACEMENT_THRESHOLD: f32 = 1e-3;
+    /// The speed under which the animation may come to rest.
+    pub const VELOCITY_THRESHOLD: f32 = 1e-2;
+
+    /// Returns an animation resting at `value`.
+    pub const fn new(spring: Spring, value: f32) -> Self {
+        Self {
+            spring,
+            value,
+            velocity: 0.0,
+            target: value,
+        }
+    }
+
+    pub const fn spring(&self) -> Spring {
+        self.spring
+    }
+
+    pub const fn value(&self) -> f32 {
+        self.value
+    }
+
+    pub const fn velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    pub const fn target(&self) -> f32 {
+        self.target
+    }
+
+    /// Replaces the spring used for the rest of the animation.
+    pub const fn set_spring(&mut self, spring: Spring) {
+        self.spring = spring;
+    }
+
+    /// Starts animating towards `target`, preserving the current velocity.
+    pub const fn animate_to(&mut self, target: f32) {
+        self.target = target;
+    }
+
+    /// Immediately moves the animation to `value` and stops it there.
+    pub const fn snap_to(&mut self, value: f32) {
+        self.value = value;
+        self.velocity = 0.0;
+        self.target = value;
+    }
+
+    /// Returns `true` if the animation has settled at its target.
+    pub fn is_at_rest(&self) -> bool {
+        (self.value - self.target).abs() < Self::DISPLACEMENT_THRESHOLD && self.velocity.abs() < Self::VELOCITY_THRESHOLD
+    }
+
+    /// Advances the animation by `delta_time` seconds and returns the new
+    /// value.
+    pub fn update(&mut self, delta_time: f32) -> f32 {
+        if self.is_at_rest() {
+            self.snap_to(self.target);
+
+            return self.value;
+        }
+
+        let (displacement, velocity) = self.spring.evaluate(self.value - self.target, self.velocity, delta_time);
+
+        self.value = self.target + displacement;
+        self.velocity = velocity;
+
+        if self.is_at_rest() {
+            self.snap_to(self.target);
+        }
+
+        self.value
+    }
+}
+
+/// Drives the progress of a [`Morph`] with a [`SpringAnimation`].
+///
+/// Under-damped springs overshoot, so the progress may temporarily leave the
+/// `0.0..=1.0` range; [`Morph::as_cubics`] extrapolates in that case.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MorphAnimation {
+    morph: Morph,
+    animation: SpringAnimation,
+}
+
+impl MorphAnimation {
+    /// Returns an animation of `morph` resting at the start shape.
+    pub const fn new(morph: Morph, spring: Spring) -> Self {
+        Self {
+            morph,
+            animation: SpringAnimation::new(spring, 0.0),
+        }
+    }
+
+    pub const fn morph(&self) -> &Morph {
+        &self.morph
+    }
+
+    pub const fn animation(&self) -> &SpringAnimation {
+        &self.animation
+    }
+
+    /// Returns the current progress of the morph.
+    pub const fn progress(&self) -> f32 {
+        self.animation.value()
+    }
+
+    /// Starts animating towards the given `progress`, e.g. `1.0` on press and
+    /// `0.0` on release.
+    pub const fn animate_to(&mut self, progress: f32) {
+        self.animation.animate_to(progress);
+    }
+
+    /// Immediately moves the morph to `progress` and stops the animation.
+    pub const fn snap_to(&mut self, progress: f32) {
+        self.animation.snap_to(progress);
+    }
+
+    /// Returns `true` if the animation has settled at its target.
+    pub fn is_at_rest(&self) -> bool {
+        self.animation.is_at_rest()
+    }
+
+    /// Advances the animation by `delta_time` seconds and returns the new
+    /// progress.
+    pub fn update(&mut self, delta_time: f32) -> f32 {
+        self.animation.update(delta_time)
+    }
+
+    /// Returns the current transition state as a list of [`Cubic`]s.
+    pub fn as_cubics(&self) -> Vec<Cubic> {
+        self.morph.as_cubics(self.progress())
+    }
+
+    /// Returns a path with the current transition state. Path is created using
+    /// the provided `T`, which should implement `PathBuilder` and `Default`
+    /// traits.
+    pub fn as_path<T: PathBuilder + Default>(&self, repeat_path: bool, close_path: bool) -> T::Path {
+        self.morph.as_path::<T>(self.progress(), repeat_path, close_path)
+    }
+
+    /// Adds the current transition state to the `builder`.
+    pub fn add_to<T: PathBuilder>(&self, builder: &mut T, repeat_path: bool, close_path: bool) {
+        add_cubics(builder, repeat_path, close_path, self.morph.cubics_at(self.progress()));
+    }
+}
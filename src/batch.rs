@@ -0,0 +1,180 @@
+use crate::{
+    Cubic, Morph,
+    geometry::Point,
+    path::{PathBuilder, add_cubics},
+};
+
+/// Evaluates one or more [`Morph`]s at many progress values at once.
+///
+/// The matched control points of all morphs are stored as a structure of
+/// arrays (separate `x` and `y` lanes for start values and deltas), so
+/// evaluating a frame is a single linear interpolation over contiguous `f32`
+/// slices, which the compiler is able to vectorize.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MorphBatch {
+    start_x: Vec<f32>,
+    start_y: Vec<f32>,
+    delta_x: Vec<f32>,
+    delta_y: Vec<f32>,
+    /// Index of the first cubic of each morph, followed by the total number of
+    /// cubics.
+    offsets: Vec<usize>,
+}
+
+impl MorphBatch {
+    /// Lays out the matched cubics of the given morphs for batch evaluation.
+    pub fn new<'a, I: IntoIterator<Item = &'a Morph>>(morphs: I) -> Self {
+        let mut batch = Self {
+            start_x: Vec::new(),
+            start_y: Vec::new(),
+            delta_x: Vec::new(),
+            delta_y: Vec::new(),
+            offsets: vec![0],
+        };
+
+        for morph in morphs {
+            for (start, end) in morph.pairs() {
+                for (from, to) in start.points.iter().zip(end.points) {
+                    batch.start_x.push(from.x);
+                    batch.start_y.push(from.y);
+                    batch.delta_x.push(to.x - from.x);
+                    batch.delta_y.push(to.y - from.y);
+                }
+            }
+
+            batch.offsets.push(batch.start_x.len() / 4);
+        }
+
+        batch
+    }
+
+    /// Returns the number of morphs in the batch.
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns `true` if the batch contains no morphs.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the total number of cubics across all morphs in the batch.
+    pub fn cubic_count(&self) -> usize {
+        self.start_x.len() / 4
+    }
+
+    /// Evaluates every morph of the batch at the given `progress`.
+    pub fn evaluate(&self, progress: f32) -> BatchFrame<'_> {
+        let mut frame = BatchFrame {
+            batch: self,
+            progress,
+            x: Vec::new(),
+            y: Vec::new(),
+        };
+
+        self.evaluate_into(progress, &mut frame);
+
+        frame
+    }
+
+    /// Evaluates every morph of the batch at the given `progress`, reusing the
+    /// allocations of `frame`.
+    pub fn evaluate_into<'a>(&'a self, progress: f32, frame: &mut BatchFrame<'a>) {
+        frame.batch = self;
+        frame.progress = progress;
+
+        lerp_into(&self.start_x, &self.delta_x, progress, &mut frame.x);
+        lerp_into(&self.start_y, &self.delta_y, progress, &mut frame.y);
+    }
+
+    /// Evaluates every morph of the batch at each of the given progress
+    /// values, e.g. to bake an animation into frames.
+    pub fn bake(&self, progress_values: &[f32]) -> Vec<BatchFrame<'_>> {
+        progress_values.iter().map(|&progress| self.evaluate(progress)).collect()
+    }
+
+    /// Same as [`MorphBatch::bake`], but evaluates frames in parallel using
+    /// `rayon`.
+    #[cfg(feature = "rayon")]
+    pub fn par_bake(&self, progress_values: &[f32]) -> Vec<BatchFrame<'_>> {
+        use rayon::prelude::*;
+
+        progress_values.par_iter().map(|&progress| self.evaluate(progress)).collect()
+    }
+}
+
+// `mul_add` compiles to a call to `fmaf` on targets without the `fma` feature,
+// which prevents this hot loop from being vectorized
+#[allow(clippy::suboptimal_flops)]
+fn lerp_into(start: &[f32], delta: &[f32], progress: f32, out: &mut Vec<f32>) {
+    out.clear();
+    out.extend(start.iter().zip(delta).map(|(start, delta)| start + delta * progress));
+}
+
+/// The transition states of all morphs of a [`MorphBatch`] at a single progress
+/// value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchFrame<'a> {
+    batch: &'a MorphBatch,
+    progress: f32,
+    x: Vec<f32>,
+    y: Vec<f32>,
+}
+
+impl BatchFrame<'_> {
+    /// Returns the progress value this frame was evaluated at.
+    pub const fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    /// Returns the raw control point coordinates of all morphs, four points per
+    /// cubic, as separate `x` and `y` lanes.
+    pub fn coordinates(&self) -> (&[f32], &[f32]) {
+        (&self.x, &self.y)
+    }
+
+    /// Returns an iterator over the cubics of the morph at `index`, with the
+    /// final anchor point stitched to the first one just like
+    /// [`Morph::as_cubics`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn cubics(&self, index: usize) -> impl ExactSizeIterator<Item = Cubic> + Clone + '_ {
+        let start = self.batch.offsets[index];
+        let end = self.batch.offsets[index + 1];
+        let first_anchor = if start < end {
+            Point::new(self.x[start * 4], self.y[start * 4])
+        } else {
+            Point::zero()
+        };
+
+        (start..end).map(move |cubic| {
+            let mut cubic_points = Cubic::from_fn(|it| Point::new(self.x[cubic * 4 + it], self.y[cubic * 4 + it]));
+
+            if cubic == end - 1 {
+                cubic_points.points[3] = first_anchor;
+            }
+
+            cubic_points
+        })
+    }
+
+    /// Returns the cubics of the morph at `index` as a list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn as_cubics(&self, index: usize) -> Vec<Cubic> {
+        self.cubics(index).collect()
+    }
+
+    /// Adds the morph at `index` to the `builder`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn add_to<T: PathBuilder>(&self, index: usize, builder: &mut T, repeat_path: bool, close_path: bool) {
+        add_cubics(builder, repeat_path, close_path, self.cubics(index));
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{
+    Cubic, DoubleMapper, MeasuredPolygon, RoundedPolygon,
+    geometry::{ANGLE_EPSILON, Point},
+    measured_polygon::MeasuredCubic,
+    measurer::LengthMeasurer,
+    path::{PathBuilder, add_cubics},
+    util::positive_modulo,
+};
+
+/// A structure designed to blend more than two [`RoundedPolygon`]s at once
+/// using arbitrary weights, e.g. barycentric weights of three shapes or
+/// bilinear weights of four shapes.
+///
+/// All shapes are brought to a common correspondence of [`Cubic`]s, using the
+/// first shape as the reference for feature matching, just like [`Morph`] does
+/// for two shapes.
+///
+/// [`Morph`]: crate::Morph
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiMorph {
+    shapes: Vec<RoundedPolygon>,
+    /// Matched cubics of each shape, all with the same length.
+    r#match: Vec<Vec<Cubic>>,
+}
+
+/// A measured shape along with the mapping of its outline progress to the
+/// outline progress of the reference shape.
+struct MappedShape {
+    measured: MeasuredPolygon<LengthMeasurer>,
+    mapper: Option<DoubleMapper>,
+    cut_point: f32,
+}
+
+impl MappedShape {
+    /// Returns the ending progress of the cubic at `index`, in the reference
+    /// shape's perspective.
+    fn end_progress(&self, cubic: &MeasuredCubic, index: usize) -> f32 {
+        if index == self.measured.cubics.len() {
+            1.0
+        } else if let Some(mapper) = &self.mapper {
+            mapper.map_back(positive_modulo(cubic.end_outline_progress + self.cut_point, 1.0))
+        } else {
+            cubic.end_outline_progress
+        }
+    }
+
+    /// Converts `progress` in the reference shape's perspective to this shape's
+    /// outline progress.
+    fn local_progress(&self, progress: f32) -> f32 {
+        self.mapper
+            .as_ref()
+            .map_or(progress, |mapper| positive_modulo(mapper.map(progress) - self.cut_point, 1.0))
+    }
+}
+
+impl MultiMorph {
+    /// Matches the [`Cubic`]s of all `shapes`, then returns an instance of
+    /// [`MultiMorph`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than 2 shapes are provided. May panic if not all cubics
+    /// of the shapes have been matched.
+    pub fn new(shapes: Vec<RoundedPolygon>) -> Self {
+        assert!(shapes.len() >= 2, "MultiMorph needs at least 2 shapes");
+
+        let r#match = Self::match_shapes(&shapes);
+
+        Self { shapes, r#match }
+    }
+
+    /// Returns the number of blended shapes.
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    /// Returns `true` if there are no shapes, which is never the case since at
+    /// least two are required.
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// Returns the blended shapes.
+    pub fn shapes(&self) -> &[RoundedPolygon] {
+        &self.shapes
+    }
+
+    /// Returns the matched cubics of the shape at `index`. All shapes have the
+    /// same number of matched cubics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn matched_cubics(&self, index: usize) -> &[Cubic] {
+        &self.r#match[index]
+    }
+
+    /// Returns the weighted combination of the shapes represented as a list of
+    /// [`Cubic`]s.
+    ///
+    /// Weights are normalized by their sum, unless it is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of weights differs from the number of shapes.
+    pub fn as_cubics(&self, weights: &[f32]) -> Vec<Cubic> {
+        assert_eq!(weights.len(), self.shapes.len(), "Expected one weight per shape");
+
+        let sum = weights.iter().sum::<f32>();
+        let scale = if sum.abs() < f32::EPSILON { 1.0 } else { 1.0 / sum };
+        let mut cubics = (0..self.r#match[0].len())
+            .map(|i| {
+                self.r#match
+                    .iter()
+                    .zip(weights)
+                    .fold(Cubic::from_fn(|_| Point::zero()), |blend, (cubics, weight)| {
+                        blend + cubics[i] * (weight * scale)
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure that the final anchor point exactly matches the first one
+        if let Some(first_anchor) = cubics.first().map(Cubic::anchor0) {
+            let last = cubics.len() - 1;
+
+            cubics[last].points[3] = first_anchor;
+        }
+
+        cubics
+    }
+
+    /// Returns the blend of three shapes with barycentric `weights`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are not exactly 3 shapes.
+    pub fn barycentric(&self, weights: [f32; 3]) -> Vec<Cubic> {
+        self.as_cubics(&weights)
+    }
+
+    /// Returns the blend of four shapes placed at the corners of a unit square
+    /// (in order: `(0, 0)`, `(1, 0)`, `(0, 1)`, `(1, 1)`) at the given
+    /// position, e.g. of a 2D joystick.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are not exactly 4 shapes.
+    pub fn bilinear(&self, u: f32, v: f32) -> Vec<Cubic> {
+        self.as_cubics(&[(1.0 - u) * (1.0 - v), u * (1.0 - v), (1.0 - u) * v, u * v])
+    }
+
+    /// Returns a path with a drawn blend of the shapes. Path is created using
+    /// the provided `T`, which should implement `PathBuilder` and `Default`
+    /// traits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of weights differs from the number of shapes.
+    pub fn as_path<T: PathBuilder + Default>(&self, weights: &[f32], repeat_path: bool, close_path: bool) -> T::Path {
+        let mut path = T::default();
+
+        self.add_to(weights, &mut path, repeat_path, close_path);
+
+        path.build()
+    }
+
+    /// Adds a blend of the shapes to the `builder`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of weights differs from the number of shapes.
+    pub fn add_to<T: PathBuilder>(&self, weights: &[f32], builder: &mut T, repeat_path: bool, close_path: bool) {
+        add_cubics(builder, repeat_path, close_path, self.as_cubics(weights));
+    }
+
+    fn match_shapes(shapes: &[RoundedPolygon]) -> Vec<Vec<Cubic>> {
+        let reference = MeasuredPolygon::measure_polygon(LengthMeasurer, &shapes[0]);
+        let mut aligned = Vec::with_capacity(shapes.len());
+
+        // Map every other shape onto the reference one, cutting and shifting it so
+        // that its outline starts where the reference one does
+        for shape in &shapes[1..] {
+            let measured = MeasuredPolygon::measure_polygon(LengthMeasurer, shape);
+            let mapper = DoubleMapper::from_features(&reference.features, &measured.features);
+            let cut_point = mapper.map(0.0);
+
+            aligned.push(MappedShape {
+                measured: measured.cut_and_shift(cut_point),
+                mapper: Some(mapper),
+                cut_point,
+            });
+        }
+
+        aligned.insert(0, MappedShape {
+            measured: reference,
+            mapper: None,
+            cut_point: 0.0,
+        });
+
+        // This is the same matching as the one used for two shapes in `Morph`: we
+        // repeatedly find the progress at which the earliest current cubic ends,
+        // then cut all other current cubics at that progress.
+        let mut ret = vec![Vec::new(); shapes.len()];
+        let mut indices = vec![1; shapes.len()];
+        let mut current = aligned.iter().map(|shape| shape.measured.cubics.first().copied()).collect::<Vec<_>>();
+
+        // Matching goes on while every shape has a current cubic
+        while let Some(cubics) = current.iter().copied().collect::<Option<Vec<_>>>() {
+            let ends = cubics
+                .iter()
+                .zip(&aligned)
+                .zip(&indices)
+                .map(|((cubic, shape), &index)| shape.end_progress(cubic, index))
+                .collect::<Vec<_>>();
+            let minb = ends.iter().copied().fold(f32::MAX, f32::min);
+
+            for (i, (shape, cubic)) in aligned.iter().zip(cubics).enumerate() {
+                let (segment, next) = if ends[i] > minb + ANGLE_EPSILON {
+                    let (a, b) = cubic.cut_at_progress(&shape.measured.measurer, shape.local_progress(minb));
+
+                    (a, Some(b))
+                } else {
+                    let next = shape.measured.cubics.get(indices[i]).copied();
+
+                    indices[i] += 1;
+
+                    (cubic, next)
+                };
+
+                ret[i].push(segment.cubic);
+                current[i] = next;
+            }
+        }
+
+        assert!(current.iter().all(Option::is_none), "Expected all Polygon's Cubic to be fully matched");
+
+        ret
+    }
+}
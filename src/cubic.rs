@@ -1,6 +1,6 @@
 use std::ops::{Add, Div, Mul};
 
-use crate::geometry::{Aabb, DISTANCE_EPSILON, GeometryExt, Point, PointTransformer};
+use crate::geometry::{Aabb, DISTANCE_EPSILON, GeometryExt, Point, PointTransformer, Vector};
 
 /// Contains 4 points forming a cubic Bézier curve: 2 anchor points at the start
 /// and end, and 2 control points between them.
@@ -84,6 +84,41 @@ impl Cubic {
         self
     }
 
+    /// Returns a list of cubic Bézier curves approximating this curve
+    /// transformed using the provided reference to type that implements
+    /// [`PointTransformer`] trait.
+    ///
+    /// Transforming only the points of a curve is exact for affine
+    /// transformations, but may be far off for non-linear ones. The curve is
+    /// therefore subdivided until its transformed midpoints are within
+    /// `tolerance` of the transformed original curve.
+    pub fn transformed_adaptive<T: PointTransformer>(self, f: &T, tolerance: f32) -> Vec<Self> {
+        let mut cubics = Vec::new();
+
+        self.transform_adaptive_into(f, tolerance, 0, &mut cubics);
+
+        cubics
+    }
+
+    fn transform_adaptive_into<T: PointTransformer>(self, f: &T, tolerance: f32, depth: usize, cubics: &mut Vec<Self>) {
+        const MAX_DEPTH: usize = 8;
+
+        let transformed = self.transformed(f);
+        let fits = depth >= MAX_DEPTH
+            || [0.25, 0.5, 0.75]
+                .into_iter()
+                .all(|t| (f.transform(self.point_on_curve(t)) - transformed.point_on_curve(t)).length() <= tolerance);
+
+        if fits {
+            cubics.push(transformed);
+        } else {
+            let (start, end) = self.split(0.5);
+
+            start.transform_adaptive_into(f, tolerance, depth + 1, cubics);
+            end.transform_adaptive_into(f, tolerance, depth + 1, cubics);
+        }
+    }
+
     /// Returns an axis-aligned bounding box describing bounds of the curve.
     ///
     /// If `approximate` is `true`, a fast but sometimes inaccurate algorithm is
@@ -233,6 +268,51 @@ impl Cubic {
         (anchor0.x - anchor1.x).abs() < DISTANCE_EPSILON && (anchor0.y - anchor1.y).abs() < DISTANCE_EPSILON
     }
 
+    /// Returns `true` if the cubic is a straight line, i.e. its control points
+    /// lie on the segment between its anchor points (within a tolerance
+    /// relative to its length), like cubics created by
+    /// [`Cubic::straight_line`].
+    pub fn is_straight(&self) -> bool {
+        let chord = self.anchor1() - self.anchor0();
+        let tolerance = DISTANCE_EPSILON.max(chord.length() * 1e-3);
+
+        if chord.length() <= DISTANCE_EPSILON {
+            return (self.control0() - self.anchor0()).length() <= tolerance && (self.control1() - self.anchor1()).length() <= tolerance;
+        }
+
+        let direction = chord.normalize();
+
+        [self.control0(), self.control1()].into_iter().all(|control| {
+            let offset = control - self.anchor0();
+
+            offset.cross(direction).abs() <= tolerance && (-tolerance..=chord.length() + tolerance).contains(&offset.dot(direction))
+        })
+    }
+
+    /// Returns points approximating the cubic with line segments that stay
+    /// within `tolerance` of the curve, excluding the first anchor point.
+    pub fn flattened(&self, tolerance: f32) -> impl Iterator<Item = Point> + use<> {
+        const MAX_SEGMENTS: f32 = 1024.0;
+
+        let cubic = *self;
+        let [p0, p1, p2, p3] = self.points;
+        // The distance to a line segment approximation is bounded by the second
+        // differences of the control points
+        let deviation = (p0 - p1 + (p2 - p1)).length().max((p1 - p2 + (p3 - p2)).length());
+        let segments = (0.75 * deviation / tolerance.max(f32::EPSILON)).sqrt().ceil().clamp(1.0, MAX_SEGMENTS);
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // Clamped above
+        let segments = segments as usize;
+
+        (1..=segments).map(move |i| {
+            if i == segments {
+                cubic.anchor1()
+            } else {
+                cubic.point_on_curve(i as f32 / segments as f32)
+            }
+        })
+    }
+
     /// Returns a point on the curve for parameter `t`, representing the
     /// proportional distance along the curve between its starting anchor and
     /// ending anchor point.
@@ -245,6 +325,24 @@ impl Cubic {
             + (self.anchor1() * (t * t * t)).to_vector()
     }
 
+    /// Returns the derivative of the curve (its velocity) for parameter `t`.
+    pub fn derivative(&self, t: f32) -> Vector {
+        let u = 1.0 - t;
+
+        (self.control0() - self.anchor0()) * (3.0 * u * u)
+            + (self.control1() - self.control0()) * (6.0 * u * t)
+            + (self.anchor1() - self.control1()) * (3.0 * t * t)
+    }
+
+    /// Returns the second derivative of the curve (its acceleration) for
+    /// parameter `t`.
+    pub fn second_derivative(&self, t: f32) -> Vector {
+        let u = 1.0 - t;
+
+        ((self.control1() - self.control0()) - (self.control0() - self.anchor0())) * (6.0 * u)
+            + ((self.anchor1() - self.control1()) - (self.control1() - self.control0())) * (6.0 * t)
+    }
+
     /// Returns two [`Cubic`]s, created by splitting this curve at the given
     /// distance of `t` between the original starting and ending anchor points.
     pub fn split(self, t: f32) -> (Self, Self) {
@@ -0,0 +1,155 @@
+use crate::{
+    ArcLengthMeasurer, Cubic, MeasuredPolygon, Measurer, Morph, RoundedPolygon,
+    path::{PathBuilder, add_cubics},
+    util::positive_modulo,
+};
+
+/// Splits closed outlines into dashes described by a pattern of arc lengths,
+/// like the SVG `stroke-dasharray` and `stroke-dashoffset` properties.
+///
+/// Dashing is done on the geometry itself, so the result is the same on every
+/// rendering backend.
+///
+/// Polygons and morphs are dashed by arc length with
+/// [`Dasher::polygon_dashes`] and [`Dasher::morph_dashes`], which measure them
+/// with [`ArcLengthMeasurer`]:
+///
+/// ```
+/// use polymorpher::{Dasher, RoundedPolygon};
+///
+/// let circle = RoundedPolygon::circle().build();
+/// let dashes = Dasher::new(&[0.1, 0.1], 0.0).polygon_dashes(&circle);
+/// ```
+///
+/// Outlines measured otherwise are dashed with [`Dasher::dashes`], using the
+/// lengths of their measurer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dasher {
+    pattern: Vec<f32>,
+    phase: f32,
+}
+
+impl Dasher {
+    /// Returns a dasher alternating between dashes and gaps with lengths from
+    /// `pattern`, starting `phase` units into the pattern.
+    ///
+    /// A pattern with an odd number of lengths is repeated to yield an even
+    /// number, e.g. `[5, 3, 2]` is the same as `[5, 3, 2, 5, 3, 2]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is empty, contains negative lengths or sums up to
+    /// zero.
+    pub fn new(pattern: &[f32], phase: f32) -> Self {
+        assert!(!pattern.is_empty(), "Dash pattern needs at least one length");
+        assert!(pattern.iter().all(|&length| length >= 0.0), "Dash pattern lengths can't be negative");
+        assert!(pattern.iter().sum::<f32>() > 0.0, "Dash pattern lengths can't all be zero");
+
+        let mut pattern = pattern.to_vec();
+
+        if pattern.len() % 2 == 1 {
+            pattern.extend_from_within(..);
+        }
+
+        Self { pattern, phase }
+    }
+
+    pub fn pattern(&self) -> &[f32] {
+        &self.pattern
+    }
+
+    pub const fn phase(&self) -> f32 {
+        self.phase
+    }
+
+    /// Returns the dashes of the outline of the `polygon` measured by arc
+    /// length, see [`Dasher::dashes`].
+    pub fn polygon_dashes(&self, polygon: &RoundedPolygon) -> Vec<Vec<Cubic>> {
+        self.dashes(&MeasuredPolygon::measure_polygon(ArcLengthMeasurer::default(), polygon))
+    }
+
+    /// Returns the dashes of the outline of the transition state of the
+    /// `morph` at the given `progress` measured by arc length, see
+    /// [`Dasher::dashes`].
+    pub fn morph_dashes(&self, morph: &Morph, progress: f32) -> Vec<Vec<Cubic>> {
+        self.dashes(&MeasuredPolygon::measure_cubics(ArcLengthMeasurer::default(), &morph.as_cubics(progress)))
+    }
+
+    /// Returns the dashes of the `outline` as open sub-paths, with lengths
+    /// given by its measurer.
+    ///
+    /// Outlines are closed, so a dash crossing the start of the outline is
+    /// returned as a single sub-path. Dashes of zero length are skipped.
+    ///
+    /// Outlines returned by [`RoundedPolygon::measured`] and
+    /// [`Morph::measured`] use [`LengthMeasurer`](crate::LengthMeasurer),
+    /// which approximates cubics with a few chords, so their dashes on strongly
+    /// curved parts come out slightly longer than the pattern.
+    pub fn dashes<M: Measurer>(&self, outline: &MeasuredPolygon<M>) -> Vec<Vec<Cubic>> {
+        let length = outline.cubics.iter().map(|cubic| cubic.measured_size).sum::<f32>();
+        let mut dashes = <Vec<Vec<Cubic>>>::new();
+
+        if length <= 0.0 {
+            return dashes;
+        }
+
+        // Find where in the pattern the outline starts
+        let mut index = 0;
+        let mut remaining = positive_modulo(self.phase, self.pattern.iter().sum());
+
+        loop {
+            if remaining < self.pattern[index] {
+                break;
+            }
+
+            remaining -= self.pattern[index];
+            index = (index + 1) % self.pattern.len();
+        }
+
+        let mut left = self.pattern[index] - remaining;
+        let mut distance = 0.0;
+        let mut starts_on_seam = false;
+        let mut ends_on_seam = false;
+
+        loop {
+            if distance >= length {
+                break;
+            }
+
+            let end = (distance + left).min(length);
+
+            if index % 2 == 0 {
+                let dash = outline.trimmed(distance / length, end / length, 0.0);
+
+                if !dash.is_empty() {
+                    starts_on_seam |= distance <= 0.0;
+                    ends_on_seam = end >= length;
+                    dashes.push(dash);
+                }
+            }
+
+            distance = end;
+            index = (index + 1) % self.pattern.len();
+            left = self.pattern[index];
+        }
+
+        // Join the dash crossing the start of the outline
+        if starts_on_seam && ends_on_seam && dashes.len() > 1 {
+            let first = dashes.remove(0);
+
+            if let Some(last) = dashes.last_mut() {
+                last.extend(first);
+            }
+        }
+
+        dashes
+    }
+
+    /// Adds the dashes of the `outline` to the `builder`, each as a separate
+    /// open contour.
+    pub fn add_to<M: Measurer, T: PathBuilder>(&self, outline: &MeasuredPolygon<M>, builder: &mut T) {
+        for dash in self.dashes(outline) {
+            add_cubics(builder, false, false, &dash);
+        }
+    }
+}
@@ -0,0 +1,330 @@
+//! Contains easing curves for remapping animation progress, including CSS-like
+//! `cubic-bezier()` and `steps()` functions and the Material 3 easing tokens.
+
+use crate::{
+    Cubic, Morph, MorphCubics,
+    geometry::Point,
+    path::{PathBuilder, add_cubics},
+};
+
+/// The standard easing, used for simple, small or utility-focused transitions.
+pub const STANDARD: CubicBezier = CubicBezier::new(0.2, 0.0, 0.0, 1.0);
+/// The standard easing for elements leaving the screen.
+pub const STANDARD_ACCELERATE: CubicBezier = CubicBezier::new(0.3, 0.0, 1.0, 1.0);
+/// The standard easing for elements entering the screen.
+pub const STANDARD_DECELERATE: CubicBezier = CubicBezier::new(0.0, 0.0, 0.0, 1.0);
+/// The emphasized easing for elements leaving the screen.
+pub const EMPHASIZED_ACCELERATE: CubicBezier = CubicBezier::new(0.3, 0.0, 0.8, 0.15);
+/// The emphasized easing for elements entering the screen.
+pub const EMPHASIZED_DECELERATE: CubicBezier = CubicBezier::new(0.05, 0.7, 0.1, 1.0);
+/// The legacy (Material 2) standard easing.
+pub const LEGACY: CubicBezier = CubicBezier::new(0.4, 0.0, 0.2, 1.0);
+/// The legacy (Material 2) easing for elements leaving the screen.
+pub const LEGACY_ACCELERATE: CubicBezier = CubicBezier::new(0.4, 0.0, 1.0, 1.0);
+/// The legacy (Material 2) easing for elements entering the screen.
+pub const LEGACY_DECELERATE: CubicBezier = CubicBezier::new(0.0, 0.0, 0.2, 1.0);
+
+/// The CSS `ease` timing function.
+pub const EASE: CubicBezier = CubicBezier::new(0.25, 0.1, 0.25, 1.0);
+/// The CSS `ease-in` timing function.
+pub const EASE_IN: CubicBezier = CubicBezier::new(0.42, 0.0, 1.0, 1.0);
+/// The CSS `ease-out` timing function.
+pub const EASE_OUT: CubicBezier = CubicBezier::new(0.0, 0.0, 0.58, 1.0);
+/// The CSS `ease-in-out` timing function.
+pub const EASE_IN_OUT: CubicBezier = CubicBezier::new(0.42, 0.0, 0.58, 1.0);
+
+/// Returns the emphasized easing, used for most Material 3 transitions.
+///
+/// Unlike the other tokens, it is described by two cubic Bézier curves.
+pub fn emphasized() -> PathEasing {
+    PathEasing::new(vec![
+        Cubic::new(
+            Point::new(0.0, 0.0),
+            Point::new(0.05, 0.0),
+            Point::new(0.133_333, 0.06),
+            Point::new(0.166_666, 0.4),
+        ),
+        Cubic::new(
+            Point::new(0.166_666, 0.4),
+            Point::new(0.208_333, 0.82),
+            Point::new(0.25, 1.0),
+            Point::new(1.0, 1.0),
+        ),
+    ])
+}
+
+/// A necessary trait for remapping the progress of an animation.
+pub trait Easing {
+    /// Returns the eased value for `fraction`, which is expected to be in
+    /// `0.0..=1.0`.
+    fn ease(&self, fraction: f32) -> f32;
+}
+
+impl<F: Fn(f32) -> f32> Easing for F {
+    fn ease(&self, fraction: f32) -> f32 {
+        self(fraction)
+    }
+}
+
+/// An easing that returns the fraction unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Linear;
+
+impl Easing for Linear {
+    fn ease(&self, fraction: f32) -> f32 {
+        fraction
+    }
+}
+
+/// An easing described by a cubic Bézier curve from `(0, 0)` to `(1, 1)`,
+/// equivalent to the CSS `cubic-bezier(x1, y1, x2, y2)` function.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    curve: Cubic,
+}
+
+impl CubicBezier {
+    /// Returns an easing using `(x1, y1)` and `(x2, y2)` as control points.
+    ///
+    /// `x1` and `x2` should be in `0.0..=1.0` so that the curve is a function
+    /// of `x`, `y1` and `y2` may be outside of it to overshoot.
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self {
+            curve: Cubic::new(Point::new(0.0, 0.0), Point::new(x1, y1), Point::new(x2, y2), Point::new(1.0, 1.0)),
+        }
+    }
+
+    /// Returns the curve describing this easing.
+    pub const fn curve(&self) -> Cubic {
+        self.curve
+    }
+}
+
+impl Easing for CubicBezier {
+    fn ease(&self, fraction: f32) -> f32 {
+        if fraction <= 0.0 {
+            0.0
+        } else if fraction >= 1.0 {
+            1.0
+        } else {
+            self.curve.point_on_curve(solve_for_x(&self.curve, fraction)).y
+        }
+    }
+}
+
+/// An easing described by a sequence of connected cubic Bézier curves from
+/// `(0, 0)` to `(1, 1)`, monotonic in `x`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathEasing {
+    cubics: Vec<Cubic>,
+}
+
+impl PathEasing {
+    /// # Panics
+    ///
+    /// Panics if `cubics` is empty.
+    pub fn new(cubics: Vec<Cubic>) -> Self {
+        assert!(!cubics.is_empty(), "Path easing needs at least one cubic");
+
+        Self { cubics }
+    }
+
+    /// Returns the curves describing this easing.
+    pub fn cubics(&self) -> &[Cubic] {
+        &self.cubics
+    }
+}
+
+impl Easing for PathEasing {
+    fn ease(&self, fraction: f32) -> f32 {
+        if fraction <= 0.0 {
+            return 0.0;
+        } else if fraction >= 1.0 {
+            return 1.0;
+        }
+
+        let cubic = self
+            .cubics
+            .iter()
+            .find(|cubic| fraction <= cubic.anchor1().x)
+            .unwrap_or(&self.cubics[self.cubics.len() - 1]);
+
+        cubic.point_on_curve(solve_for_x(cubic, fraction)).y
+    }
+}
+
+/// Where the jumps of a [`Steps`] easing happen, as in the CSS `steps()`
+/// function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StepPosition {
+    /// The first jump happens when the animation begins.
+    JumpStart,
+    /// The last jump happens when the animation ends.
+    #[default]
+    JumpEnd,
+    /// There is no jump at either end.
+    JumpNone,
+    /// There are jumps at both ends.
+    JumpBoth,
+}
+
+/// An easing dividing the progress into a number of equal steps, equivalent
+/// to the CSS `steps(count, position)` function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Steps {
+    count: usize,
+    position: StepPosition,
+}
+
+impl Steps {
+    /// # Panics
+    ///
+    /// Panics if `count` is zero, or if it is one and `position` is
+    /// [`StepPosition::JumpNone`].
+    pub fn new(count: usize, position: StepPosition) -> Self {
+        assert!(count > 0, "Steps easing needs at least one step");
+        assert!(
+            count > 1 || position != StepPosition::JumpNone,
+            "Steps easing with no jumps at either end needs at least two steps"
+        );
+
+        Self { count, position }
+    }
+}
+
+impl Easing for Steps {
+    fn ease(&self, fraction: f32) -> f32 {
+        let count = self.count as f32;
+        let mut step = (fraction * count).floor();
+
+        if matches!(self.position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+            step += 1.0;
+        }
+
+        let jumps = match self.position {
+            StepPosition::JumpStart | StepPosition::JumpEnd => count,
+            StepPosition::JumpNone => count - 1.0,
+            StepPosition::JumpBoth => count + 1.0,
+        };
+
+        if fraction >= 0.0 && step < 0.0 {
+            step = 0.0;
+        }
+
+        if fraction <= 1.0 && step > jumps {
+            step = jumps;
+        }
+
+        step / jumps
+    }
+}
+
+/// Finds the parameter `t` of a curve that is monotonic in `x` at which it
+/// reaches the given `x`.
+fn solve_for_x(cubic: &Cubic, x: f32) -> f32 {
+    const EPSILON: f32 = 1e-6;
+
+    let (start, end) = (cubic.anchor0().x, cubic.anchor1().x);
+
+    if end - start < EPSILON {
+        return 0.0;
+    }
+
+    // Newton's method converges quickly for most curves...
+    let mut t = (x - start) / (end - start);
+
+    for _ in 0..8 {
+        let error = cubic.point_on_curve(t).x - x;
+
+        if error.abs() < EPSILON {
+            return t;
+        }
+
+        let slope = cubic.derivative(t).x;
+
+        if slope.abs() < EPSILON {
+            break;
+        }
+
+        t -= error / slope;
+    }
+
+    // ...but falls back to bisection when the slope gets too flat
+    let (mut low, mut high) = (0.0f32, 1.0f32);
+
+    t = (x - start) / (end - start);
+
+    for _ in 0..32 {
+        let value = cubic.point_on_curve(t).x;
+
+        if (value - x).abs() < EPSILON {
+            break;
+        }
+
+        if value < x {
+            low = t;
+        } else {
+            high = t;
+        }
+
+        t = low.midpoint(high);
+    }
+
+    t
+}
+
+/// A [`Morph`] with an [`Easing`] applied to its progress.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EasedMorph<E> {
+    morph: Morph,
+    easing: E,
+}
+
+impl<E: Easing> EasedMorph<E> {
+    pub const fn new(morph: Morph, easing: E) -> Self {
+        Self { morph, easing }
+    }
+
+    pub const fn morph(&self) -> &Morph {
+        &self.morph
+    }
+
+    pub const fn easing(&self) -> &E {
+        &self.easing
+    }
+
+    /// Returns the progress of the underlying [`Morph`] for the given linear
+    /// `progress`.
+    pub fn eased_progress(&self, progress: f32) -> f32 {
+        self.easing.ease(progress)
+    }
+
+    /// Returns the transition state at the eased `progress` value represented
+    /// as a list of [`Cubic`]s.
+    pub fn as_cubics(&self, progress: f32) -> Vec<Cubic> {
+        self.morph.as_cubics(self.eased_progress(progress))
+    }
+
+    /// Returns an iterator that lazily yields the transition state at the
+    /// eased `progress` value.
+    pub fn cubics_at(&self, progress: f32) -> MorphCubics<'_> {
+        self.morph.cubics_at(self.eased_progress(progress))
+    }
+
+    /// Returns a path with a drawn transition state (based on the eased
+    /// `progress`). Path is created using the provided `T`, which should
+    /// implement `PathBuilder` and `Default` traits.
+    pub fn as_path<T: PathBuilder + Default>(&self, progress: f32, repeat_path: bool, close_path: bool) -> T::Path {
+        let mut path = T::default();
+
+        self.add_to(progress, &mut path, repeat_path, close_path);
+
+        path.build()
+    }
+
+    /// Adds a transition state (based on the eased `progress`) to the
+    /// `builder`.
+    pub fn add_to<T: PathBuilder>(&self, progress: f32, builder: &mut T, repeat_path: bool, close_path: bool) {
+        add_cubics(builder, repeat_path, close_path, self.cubics_at(progress));
+    }
+}
@@ -0,0 +1,262 @@
+//! Contains helpers for painting polygons and morphs with `egui`.
+//!
+//! `epaint` has no closed cubic path type and only fills convex paths, so
+//! outlines are flattened and triangulated by ear clipping, which fills
+//! concave outlines as well.
+
+use ::egui::{
+    Color32, Mesh, Pos2, Response, Sense, Shape, Stroke, Ui, Vec2, Widget,
+    epaint::{PathShape, PathStroke},
+};
+
+use crate::{
+    Fit, Morph, MorphFrame, RoundedPolygon,
+    geometry::{Aabb, Point, Vector},
+    util::flatten,
+};
+
+/// The default distance in points within which flattened outlines stay to the
+/// exact ones.
+pub const TOLERANCE: f32 = 0.1;
+
+const fn pos(point: Point) -> Pos2 {
+    Pos2::new(point.x, point.y)
+}
+
+/// Returns the triangles covering the closed outline made of `points`, as
+/// indices of the points, found by repeatedly clipping ears: corners whose
+/// triangle contains no other point.
+///
+/// Outlines crossing themselves, which morphs may produce midway, can run
+/// out of ears, in which case a corner is clipped anyway so that the whole
+/// outline is still covered.
+fn triangulate(points: &[Point]) -> Vec<[usize; 3]> {
+    let n = points.len();
+    let area = (0..n).map(|i| points[i].to_vector().cross(points[(i + 1) % n].to_vector())).sum::<f32>();
+    let side = if area < 0.0 { -1.0 } else { 1.0 };
+    // Positive if `c` is to the inner side of the line from `a` to `b`
+    let turn = |a: Point, b: Point, c: Point| (b - a).cross(c - b) * side;
+
+    let mut ring = (0..n).collect::<Vec<_>>();
+    let mut triangles = Vec::with_capacity(n.saturating_sub(2));
+    let mut index = 0;
+    let mut misses = 0;
+
+    while ring.len() > 3 {
+        let len = ring.len();
+        let corner = [ring[(index + len - 1) % len], ring[index], ring[(index + 1) % len]];
+        let [from, at, to] = corner.map(|i| points[i]);
+        let is_ear = turn(from, at, to) >= 0.0
+            && !ring.iter().any(|&i| {
+                let point = points[i];
+
+                !corner.contains(&i) && turn(from, at, point) > 0.0 && turn(at, to, point) > 0.0 && turn(to, from, point) > 0.0
+            });
+
+        if is_ear || misses >= len {
+            triangles.push(corner);
+            ring.remove(index);
+            misses = 0;
+            // The previous corner changed, so it's checked again
+            index = (index + len - 2) % (len - 1);
+        } else {
+            index = (index + 1) % len;
+            misses += 1;
+        }
+    }
+
+    if let &[a, b, c] = ring.as_slice() {
+        triangles.push([a, b, c]);
+    }
+
+    triangles
+}
+
+/// Returns a mesh filling the closed outline made of `points` with `color`.
+///
+/// If `feathering` is positive, the outline gets an anti-aliased edge of that
+/// width, fading out to transparent. Usually it's the size of a physical
+/// pixel in points, i.e. `1.0 / pixels_per_point`. The edge is placed outside
+/// of the outline whichever direction it winds in.
+pub fn fill_mesh(points: &[Point], color: Color32, feathering: f32) -> Mesh {
+    let n = points.len();
+    let mut mesh = Mesh::default();
+
+    if n < 3 {
+        return mesh;
+    }
+
+    let feathered = feathering > 0.0;
+    let index = |i: usize| u32::try_from(i).unwrap_or(u32::MAX);
+
+    mesh.reserve_vertices(if feathered { 2 * n } else { n });
+    mesh.reserve_triangles(if feathered { 3 * n - 2 } else { n - 2 });
+
+    // Outlines winding in the opposite direction have normals on the other side
+    let area = (0..n).map(|i| points[i].to_vector().cross(points[(i + 1) % n].to_vector())).sum::<f32>();
+    let side = if area < 0.0 { -1.0 } else { 1.0 };
+
+    // Outward normals at the points, averaged from the neighbouring segments
+    let normals = (0..n)
+        .map(|i| {
+            let direction = points[(i + 1) % n] - points[(i + n - 1) % n];
+
+            Vector::new(direction.y, -direction.x).try_normalize().unwrap_or_default() * side
+        })
+        .collect::<Vec<_>>();
+
+    for (point, normal) in points.iter().zip(&normals) {
+        mesh.colored_vertex(pos(*point - *normal * (feathering.max(0.0) / 2.0)), color);
+    }
+
+    for [a, b, c] in triangulate(points) {
+        mesh.add_triangle(index(a), index(b), index(c));
+    }
+
+    if feathered {
+        for (point, normal) in points.iter().zip(&normals) {
+            mesh.colored_vertex(pos(*point + *normal * (feathering / 2.0)), Color32::TRANSPARENT);
+        }
+
+        for i in 0..n {
+            let j = (i + 1) % n;
+
+            mesh.add_triangle(index(i), index(j), index(n + i));
+            mesh.add_triangle(index(j), index(n + j), index(n + i));
+        }
+    }
+
+    mesh
+}
+
+/// Returns a path stroking the closed outline made of `points`.
+pub fn stroke_path(points: &[Point], stroke: impl Into<PathStroke>) -> PathShape {
+    PathShape::closed_line(points.iter().copied().map(pos).collect(), stroke)
+}
+
+/// Returns a shape filling and stroking the closed outline made of `points`,
+/// see [`fill_mesh`] and [`stroke_path`].
+pub fn shape(points: &[Point], fill: Color32, stroke: Stroke, feathering: f32) -> Shape {
+    let mut shapes = Vec::with_capacity(2);
+
+    if fill != Color32::TRANSPARENT {
+        shapes.push(Shape::mesh(fill_mesh(points, fill, feathering)));
+    }
+
+    if !stroke.is_empty() {
+        shapes.push(Shape::Path(stroke_path(points, stroke)));
+    }
+
+    Shape::Vec(shapes)
+}
+
+impl RoundedPolygon {
+    /// Returns an `egui` shape filling and stroking this polygon, flattened
+    /// within [`TOLERANCE`], see [`shape`].
+    pub fn to_egui_shape(&self, fill: Color32, stroke: Stroke, feathering: f32) -> Shape {
+        shape(&self.flattened(TOLERANCE), fill, stroke, feathering)
+    }
+}
+
+impl MorphFrame<'_> {
+    /// Returns an `egui` shape filling and stroking this transition state,
+    /// flattened within [`TOLERANCE`], see [`shape`].
+    pub fn to_egui_shape(&self, fill: Color32, stroke: Stroke, feathering: f32) -> Shape {
+        shape(&self.flattened(TOLERANCE), fill, stroke, feathering)
+    }
+}
+
+/// A widget painting a [`Morph`] fitted into its rectangle, which transitions
+/// to the end polygon while it's hovered or pressed.
+///
+/// ```rust,ignore
+/// ui.add(MorphWidget::new(&morph).with_size(Vec2::splat(48.0)).with_fill(Color32::LIGHT_BLUE));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct MorphWidget<'a> {
+    morph: &'a Morph,
+    size: Vec2,
+    fill: Color32,
+    stroke: Stroke,
+    fit: Fit,
+    animation_time: f32,
+}
+
+impl<'a> MorphWidget<'a> {
+    pub fn new(morph: &'a Morph) -> Self {
+        Self {
+            morph,
+            size: Vec2::splat(32.0),
+            fill: Color32::GRAY,
+            stroke: Stroke::NONE,
+            fit: Fit::default(),
+            animation_time: 0.2,
+        }
+    }
+
+    /// Sets the size of the widget.
+    #[must_use]
+    pub const fn with_size(mut self, size: Vec2) -> Self {
+        self.size = size;
+
+        self
+    }
+
+    /// Sets the color filling the morph.
+    #[must_use]
+    pub const fn with_fill(mut self, fill: Color32) -> Self {
+        self.fill = fill;
+
+        self
+    }
+
+    /// Sets the stroke around the morph.
+    #[must_use]
+    pub const fn with_stroke(mut self, stroke: Stroke) -> Self {
+        self.stroke = stroke;
+
+        self
+    }
+
+    /// Sets how the morph is fitted into the rectangle of the widget.
+    #[must_use]
+    pub const fn with_fit(mut self, fit: Fit) -> Self {
+        self.fit = fit;
+
+        self
+    }
+
+    /// Sets the duration of the transition in seconds.
+    #[must_use]
+    pub const fn with_animation_time(mut self, animation_time: f32) -> Self {
+        self.animation_time = animation_time;
+
+        self
+    }
+}
+
+impl Widget for MorphWidget<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let (rect, response) = ui.allocate_exact_size(self.size, Sense::click());
+
+        if ui.is_rect_visible(rect) {
+            let active = response.hovered() || response.is_pointer_button_down_on();
+            let progress = ui.ctx().animate_bool_with_time(response.id, active, self.animation_time);
+            let frame = self.morph.frame(progress);
+
+            // The whole transition is fitted, so the shape doesn't jump around while
+            // animating
+            let transform = self.fit.transform(
+                self.morph.bounds(0.0..=1.0, true),
+                None,
+                Aabb::new(Point::new(rect.min.x, rect.min.y), Point::new(rect.max.x, rect.max.y)),
+            );
+            let points = flatten(frame.cubics().map(|cubic| cubic.transformed(&transform)), TOLERANCE);
+            let feathering = ui.ctx().pixels_per_point().recip();
+
+            ui.painter().add(shape(&points, self.fill, self.stroke, feathering));
+        }
+
+        response
+    }
+}
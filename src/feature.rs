@@ -1,4 +1,7 @@
-use crate::{cubic::Cubic, geometry::PointTransformer};
+use crate::{
+    cubic::Cubic,
+    geometry::{Angle, Matrix2, Point, PointTransformer, TransformExt, Vector},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum FeatureType {
@@ -47,6 +50,61 @@ impl Feature {
         }
     }
 
+    /// Returns a [`Feature`] with cubics transformed using the provided
+    /// reference to type that implements [`PointTransformer`] trait, subdivided
+    /// as needed to stay within `tolerance`, see
+    /// [`Cubic::transformed_adaptive`].
+    #[must_use]
+    pub fn transformed_adaptive<T: PointTransformer>(self, f: &T, tolerance: f32) -> Self {
+        Self {
+            ty: self.ty,
+            cubics: self.cubics.into_iter().flat_map(|cubic| cubic.transformed_adaptive(f, tolerance)).collect(),
+        }
+    }
+
+    /// Returns a [`Feature`] with cubics transformed using the provided affine
+    /// `matrix`.
+    ///
+    /// If the transformation flips winding (e.g. a mirror), the cubics are also
+    /// reversed, so the feature keeps the winding direction of its polygon and
+    /// `convex` stays correct.
+    #[must_use]
+    pub fn transformed_affine(self, matrix: &Matrix2) -> Self {
+        let feature = self.transformed(matrix);
+
+        if matrix.flips_winding() { feature.reversed() } else { feature }
+    }
+
+    /// Returns a [`Feature`] rotated by `angle` around `center`.
+    #[must_use]
+    pub fn rotated(self, angle: Angle, center: Point) -> Self {
+        self.transformed_affine(&Matrix2::rotation_about(angle, center))
+    }
+
+    /// Returns a [`Feature`] scaled by `x` and `y` factors relative to
+    /// `center`.
+    #[must_use]
+    pub fn scaled(self, x: f32, y: f32, center: Point) -> Self {
+        self.transformed_affine(&Matrix2::scale_about(x, y, center))
+    }
+
+    /// Returns a [`Feature`] reflected across the line going through `origin`
+    /// in the given `direction`.
+    #[must_use]
+    pub fn mirrored(self, origin: Point, direction: Vector) -> Self {
+        self.transformed_affine(&Matrix2::mirror(origin, direction))
+    }
+
+    /// Returns a [`Feature`] with cubics in reverse order, each of them
+    /// reversed as well.
+    #[must_use]
+    pub(crate) fn reversed(self) -> Self {
+        Self {
+            ty: self.ty,
+            cubics: self.cubics.into_iter().rev().map(Cubic::reversed).collect(),
+        }
+    }
+
     /// Returns `true` if the feature type is corner.
     pub const fn is_corner(&self) -> bool {
         matches!(self.ty, FeatureType::Corner { .. })
@@ -81,6 +81,7 @@ impl DoubleMapper {
             for &f2 in &filtered_features2 {
                 let distance = feature_dist_squared(&f1.feature, &f2.feature);
 
+                #[allow(clippy::float_cmp)] // `f32::MAX` is an exact sentinel value
                 if distance != f32::MAX {
                     distance_vertex_list.push(DistanceVertex { distance, f1, f2 });
                 }
@@ -0,0 +1,131 @@
+use crate::geometry::{Aabb, DISTANCE_EPSILON, Matrix2, Point, Vector};
+
+/// Describes how a shape is scaled to fit into a rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FitMode {
+    /// Scales uniformly so the whole shape fits inside the rectangle.
+    #[default]
+    Contain,
+    /// Scales uniformly so the shape covers the whole rectangle, possibly
+    /// extending beyond it.
+    Cover,
+    /// Scales each axis independently so the shape exactly fills the
+    /// rectangle.
+    Fill,
+    /// Keeps the size of the shape, only positioning it.
+    None,
+}
+
+/// Describes where a shape is placed inside a rectangle when there is extra
+/// space in either direction, as fractions of that space: `0.0` aligns to the
+/// top/left edge and `1.0` to the bottom/right edge.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Alignment {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Alignment {
+    pub const BOTTOM_CENTER: Self = Self::new(0.5, 1.0);
+    pub const BOTTOM_LEFT: Self = Self::new(0.0, 1.0);
+    pub const BOTTOM_RIGHT: Self = Self::new(1.0, 1.0);
+    pub const CENTER: Self = Self::new(0.5, 0.5);
+    pub const CENTER_LEFT: Self = Self::new(0.0, 0.5);
+    pub const CENTER_RIGHT: Self = Self::new(1.0, 0.5);
+    pub const TOP_CENTER: Self = Self::new(0.5, 0.0);
+    pub const TOP_LEFT: Self = Self::new(0.0, 0.0);
+    pub const TOP_RIGHT: Self = Self::new(1.0, 0.0);
+
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Default for Alignment {
+    fn default() -> Self {
+        Self::CENTER
+    }
+}
+
+/// Describes how [`RoundedPolygon::fit_into`] and [`Morph::fit_into`] place a
+/// shape into a rectangle.
+///
+/// [`RoundedPolygon::fit_into`]: crate::RoundedPolygon::fit_into
+/// [`Morph::fit_into`]: crate::Morph::fit_into
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Fit {
+    pub mode: FitMode,
+    pub alignment: Alignment,
+    /// If `true`, exact bounds of the cubics are used instead of the faster
+    /// approximate ones, see [`Cubic::aabb`](crate::Cubic::aabb).
+    pub exact_bounds: bool,
+    /// If `true`, the shape is positioned by the centroid of its area instead
+    /// of the center of its bounds, which looks more balanced for asymmetric
+    /// shapes (e.g. a triangle) but may move it slightly outside of the
+    /// rectangle.
+    pub optical_centering: bool,
+}
+
+impl Fit {
+    pub const fn new(mode: FitMode, alignment: Alignment) -> Self {
+        Self {
+            mode,
+            alignment,
+            exact_bounds: false,
+            optical_centering: false,
+        }
+    }
+
+    /// Makes the fit use exact bounds of the shape.
+    #[must_use]
+    pub const fn exact_bounds(mut self) -> Self {
+        self.exact_bounds = true;
+
+        self
+    }
+
+    /// Makes the fit position the shape by the centroid of its area.
+    #[must_use]
+    pub const fn optical_centering(mut self) -> Self {
+        self.optical_centering = true;
+
+        self
+    }
+
+    /// Returns a transformation mapping a shape with the given `bounds` and
+    /// `centroid` into `rect`.
+    pub(crate) fn transform(&self, bounds: Aabb, centroid: Option<Point>, rect: Aabb) -> impl Fn(Point) -> Point + use<> {
+        let matrix = self.matrix(bounds, centroid, rect);
+
+        move |point: Point| matrix.transform_point(point)
+    }
+
+    /// Returns the matrix of the transformation returned by
+    /// [`Fit::transform`].
+    pub(crate) fn matrix(&self, bounds: Aabb, centroid: Option<Point>, rect: Aabb) -> Matrix2 {
+        let size = bounds.size();
+        let rect_size = rect.size();
+        let ratio = |target: f32, current: f32| if current > DISTANCE_EPSILON { target / current } else { f32::INFINITY };
+        let (x, y) = (ratio(rect_size.width, size.width), ratio(rect_size.height, size.height));
+
+        let scale = match self.mode {
+            // A degenerate axis can't be covered, so only the other one is considered
+            FitMode::Cover if x.is_finite() && y.is_finite() => Vector::splat(x.max(y)),
+            FitMode::Contain | FitMode::Cover => Vector::splat(x.min(y)),
+            FitMode::Fill => Vector::new(x, y),
+            FitMode::None => Vector::splat(1.0),
+        };
+        // Degenerate axes (e.g. of a zero-sized shape) are left unscaled
+        let scale = Vector::new(if scale.x.is_finite() { scale.x } else { 1.0 }, if scale.y.is_finite() { scale.y } else { 1.0 });
+
+        let space = rect_size.to_vector() - size.to_vector().component_mul(scale);
+        let mut offset =
+            rect.min.to_vector() + space.component_mul(Vector::new(self.alignment.x, self.alignment.y)) - bounds.min.to_vector().component_mul(scale);
+
+        if let Some(centroid) = centroid.filter(|_| self.optical_centering) {
+            offset += (bounds.center() - centroid).component_mul(scale);
+        }
+
+        Matrix2::scale(scale.x, scale.y).then_translate(offset)
+    }
+}
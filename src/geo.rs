@@ -0,0 +1,268 @@
+//! Conversions between [`RoundedPolygon`]s and `geo` polygons.
+
+use core::f32::consts::{FRAC_PI_6, PI};
+
+use geo_types::{Coord, LineString, MultiPolygon, Polygon};
+
+use crate::{
+    CornerRounding, Cubic, RoundedPolygon,
+    geometry::{Point, Vector},
+    util::centroid,
+};
+
+/// Turns at points of a ring at least this large are sharp corners.
+const SHARP_ANGLE: f32 = FRAC_PI_6;
+
+/// Turns smaller than this are ignored.
+const MIN_ANGLE: f32 = PI / 180.0;
+
+/// Arcs turning more than this are split into several rounded corners.
+const MAX_ARC_ANGLE: f32 = PI * 5.0 / 9.0;
+
+/// How the points of a `geo` ring become the vertices of a [`RoundedPolygon`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VertexRounding {
+    /// Every point of the ring is a vertex with the given rounding.
+    Uniform(CornerRounding),
+    /// Runs of points along arcs, like the ones of a flattened
+    /// [`RoundedPolygon`], are detected and replaced with a single rounded
+    /// vertex where the neighbouring straight parts meet. The remaining points
+    /// are unrounded vertices.
+    Detected,
+}
+
+impl RoundedPolygon {
+    /// Returns a `geo` polygon with the outline of this polygon flattened
+    /// within `tolerance`.
+    pub fn to_geo_polygon(&self, tolerance: f32) -> Polygon<f64> {
+        Polygon::new(ring(&self.flattened(tolerance)), Vec::new())
+    }
+
+    /// Returns a `geo` multi polygon with the outlines of `polygons` flattened
+    /// within `tolerance`.
+    pub fn to_geo_multi_polygon<'a, I: IntoIterator<Item = &'a Self>>(polygons: I, tolerance: f32) -> MultiPolygon<f64> {
+        polygons.into_iter().map(|polygon| polygon.to_geo_polygon(tolerance)).collect()
+    }
+
+    /// Returns a polygon with the vertices of the exterior ring of `polygon`,
+    /// rounded as specified by `rounding`. Interior rings are ignored.
+    ///
+    /// Repeated points and points where the ring doesn't turn are skipped, and
+    /// the vertices are reversed if needed to get the winding of the polygons
+    /// in this crate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the exterior ring has fewer than 3 vertices.
+    pub fn from_geo_polygon(polygon: &Polygon<f64>, rounding: VertexRounding) -> Self {
+        Self::try_from_geo_polygon(polygon, rounding).expect("Polygons must have at least 3 vertices")
+    }
+
+    /// Returns polygons with the vertices of the exterior rings of `polygons`,
+    /// see [`RoundedPolygon::from_geo_polygon`]. Polygons with fewer than 3
+    /// vertices are skipped.
+    pub fn from_geo_multi_polygon(polygons: &MultiPolygon<f64>, rounding: VertexRounding) -> Vec<Self> {
+        polygons.iter().filter_map(|polygon| Self::try_from_geo_polygon(polygon, rounding)).collect()
+    }
+
+    fn try_from_geo_polygon(polygon: &Polygon<f64>, rounding: VertexRounding) -> Option<Self> {
+        let points = points(polygon.exterior());
+
+        let (vertices, roundings) = match rounding {
+            VertexRounding::Uniform(rounding) => {
+                let vertices = corners(&points);
+                let roundings = vec![rounding; vertices.len()];
+
+                (vertices, roundings)
+            }
+            VertexRounding::Detected => detect_roundings(&points),
+        };
+
+        if vertices.len() < 3 {
+            return None;
+        }
+
+        let center = centroid(lines(&points)).unwrap_or_else(|| Point::splat(f32::MIN));
+
+        Some(Self::from_vertices(&vertices, CornerRounding::UNROUNDED, &roundings, center))
+    }
+}
+
+fn ring(points: &[Point]) -> LineString<f64> {
+    points
+        .iter()
+        .map(|point| Coord {
+            x: f64::from(point.x),
+            y: f64::from(point.y),
+        })
+        .collect()
+}
+
+/// Returns the distinct points of `ring` with a positive signed area, without
+/// repeating the first point at the end.
+fn points(ring: &LineString<f64>) -> Vec<Point> {
+    #[allow(clippy::cast_possible_truncation)] // Polygons in this crate use `f32`
+    let mut points = ring.coords().map(|coord| Point::new(coord.x as f32, coord.y as f32)).collect::<Vec<_>>();
+
+    points.dedup();
+
+    while points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    let area = (0..points.len())
+        .map(|i| points[i].to_vector().cross(points[(i + 1) % points.len()].to_vector()))
+        .sum::<f32>();
+
+    if area < 0.0 {
+        points.reverse();
+    }
+
+    points
+}
+
+fn lines(points: &[Point]) -> impl Iterator<Item = Cubic> + '_ {
+    (0..points.len()).map(|i| Cubic::straight_line(points[i], points[(i + 1) % points.len()]))
+}
+
+/// Returns the signed angle from `from` to `to`.
+fn turn(from: Vector, to: Vector) -> f32 {
+    from.cross(to).atan2(from.dot(to))
+}
+
+/// Returns the points where the closed outline made of `points` turns.
+fn corners(points: &[Point]) -> Vec<Point> {
+    let n = points.len();
+
+    (0..n)
+        .filter(|&i| turn(points[i] - points[(i + n - 1) % n], points[(i + 1) % n] - points[i]).abs() >= MIN_ANGLE)
+        .map(|i| points[i])
+        .collect()
+}
+
+/// Returns the vertices and roundings of a polygon close to the closed outline
+/// made of `points`.
+///
+/// Segments are straight if the outline turns little around them compared to
+/// the size of the outline, or if they end at a sharp corner. Runs of the
+/// other segments are arcs, which are split at chords where the outline starts
+/// turning the other way, and then so that none of them turns more than
+/// [`MAX_ARC_ANGLE`]. Each arc becomes a vertex where the lines
+/// through the straight segments around it meet, rounded with the radius of a
+/// circle of the same length and turn.
+fn detect_roundings(points: &[Point]) -> (Vec<Point>, Vec<CornerRounding>) {
+    let n = points.len();
+
+    if n < 3 {
+        return (Vec::new(), Vec::new());
+    }
+
+    let directions = (0..n).map(|i| points[(i + 1) % n] - points[i]).collect::<Vec<_>>();
+    let turns = (0..n).map(|i| turn(directions[(i + n - 1) % n], directions[i])).collect::<Vec<_>>();
+    let sharp = turns.iter().map(|turn| turn.abs() >= SHARP_ANGLE).collect::<Vec<_>>();
+
+    let (min, max) = points
+        .iter()
+        .fold((points[0], points[0]), |(min, max), point| (min.min(*point), max.max(*point)));
+    let size = (max - min).x.max((max - min).y);
+
+    let mut anchors = (0..n)
+        .map(|i| {
+            let j = (i + 1) % n;
+            let smooth_turn = |k: usize| if sharp[k] { 0.0 } else { turns[k].abs() };
+
+            sharp[i] || sharp[j] || f32::midpoint(smooth_turn(i), smooth_turn(j)) * size < directions[i].length()
+        })
+        .collect::<Vec<_>>();
+
+    let chords = anchors.iter().map(|anchor| !anchor).collect::<Vec<_>>();
+
+    // A closed curve without straight parts starts where it turns the other
+    // way, or anywhere if it's convex
+    if !anchors.contains(&true) {
+        anchors[(0..n).find(|&i| turns[i] * turns[(i + 1) % n] < 0.0).unwrap_or(0)] = true;
+    }
+
+    // Split the arcs where they start turning the other way, since turns of
+    // convex and concave parts would cancel each other out
+    let straight = anchors.clone();
+
+    for start in (0..n).filter(|&i| straight[i]) {
+        let mut turning = 0.0;
+
+        for i in (1..=n).map(|k| (start + k) % n) {
+            if turns[i].abs() >= MIN_ANGLE {
+                if turning * turns[i] < 0.0 {
+                    anchors[(i + n - 1) % n] = true;
+                }
+
+                turning = turns[i].signum();
+            }
+
+            if straight[i] {
+                break;
+            }
+        }
+    }
+
+    // Split the arcs between straight segments
+    let straight = anchors.clone();
+
+    for start in (0..n).filter(|&i| straight[i]) {
+        let run = (1..n).map(|k| (start + k) % n).take_while(|&i| !anchors[i]).collect::<Vec<_>>();
+
+        if run.is_empty() {
+            continue;
+        }
+
+        let total = (1..=run.len() + 1).map(|k| turns[(start + k) % n]).sum::<f32>();
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // The count is small and positive
+        let pieces = (total.abs() / MAX_ARC_ANGLE).ceil() as usize;
+        let mut turned = 0.0;
+        let mut next = 1;
+
+        for &i in &run {
+            turned += turns[i];
+
+            #[allow(clippy::cast_precision_loss)] // The count is small
+            if next < pieces && turned.abs() >= total.abs() * next as f32 / pieces as f32 {
+                anchors[i] = true;
+                next += 1;
+            }
+        }
+    }
+
+    let anchors = (0..n).filter(|&i| anchors[i]).collect::<Vec<_>>();
+    let mut vertices = Vec::with_capacity(anchors.len());
+    let mut roundings = Vec::with_capacity(anchors.len());
+
+    for (k, &from) in anchors.iter().enumerate() {
+        let to = anchors[(k + 1) % anchors.len()];
+        let angle = turn(directions[from], directions[to]);
+
+        if angle.abs() < MIN_ANGLE {
+            continue;
+        }
+
+        let gap = (1..n).map(|k| (from + k) % n).take_while(|&i| i != to).collect::<Vec<_>>();
+
+        if gap.is_empty() {
+            vertices.push(points[to]);
+            roundings.push(CornerRounding::UNROUNDED);
+
+            continue;
+        }
+
+        // Arcs split at a chord share its length
+        let length = gap.iter().map(|&i| directions[i].length()).sum::<f32>()
+            + [from, to].iter().filter(|&&i| chords[i]).map(|&i| directions[i].length() / 2.0).sum::<f32>();
+        let denominator = directions[from].cross(directions[to]);
+        let along = (points[to] - points[from]).cross(directions[to]) / denominator;
+
+        vertices.push(points[from] + directions[from] * along);
+        roundings.push(CornerRounding::new(length / angle.abs()));
+    }
+
+    (vertices, roundings)
+}
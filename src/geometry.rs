@@ -91,8 +91,70 @@ impl<F: Fn(Point) -> Point> PointTransformer for F {
     }
 }
 
+impl PointTransformer for Matrix2 {
+    fn transform(&self, point: Point) -> Point {
+        self.transform_point(point)
+    }
+}
+
 impl PointTransformer for Matrix3 {
     fn transform(&self, point: Point) -> Point {
         self.transform_point2d(point).unwrap_or(point)
     }
 }
+
+/// An extension trait with constructors of common affine transformations.
+///
+/// Transformations are composed with [`Matrix2::then`], e.g.
+/// `Matrix2::skew(x, y).then(&Matrix2::rotation_about(angle, center))`.
+pub trait TransformExt {
+    /// Returns a rotation by `angle` around `center`.
+    fn rotation_about(angle: Angle, center: Point) -> Self;
+    /// Returns a scale by `x` and `y` factors relative to `center`.
+    fn scale_about(x: f32, y: f32, center: Point) -> Self;
+    /// Returns a skew by `x` along the X axis and by `y` along the Y axis.
+    fn skew(x: Angle, y: Angle) -> Self;
+    /// Returns a reflection across the line going through `origin` in the given
+    /// `direction`.
+    fn mirror(origin: Point, direction: Vector) -> Self;
+    /// Returns `true` if the transformation reverses the winding direction of
+    /// shapes, i.e. it contains a reflection.
+    fn flips_winding(&self) -> bool;
+    /// Returns `true` if the transformation only translates, rotates, reflects
+    /// and scales uniformly, so it keeps circles circular.
+    fn is_similarity(&self) -> bool;
+}
+
+impl TransformExt for Matrix2 {
+    fn rotation_about(angle: Angle, center: Point) -> Self {
+        Self::translation(-center.x, -center.y).then_rotate(angle).then_translate(center.to_vector())
+    }
+
+    fn scale_about(x: f32, y: f32, center: Point) -> Self {
+        Self::translation(-center.x, -center.y).then_scale(x, y).then_translate(center.to_vector())
+    }
+
+    fn skew(x: Angle, y: Angle) -> Self {
+        Self::new(1.0, y.radians.tan(), x.radians.tan(), 1.0, 0.0, 0.0)
+    }
+
+    fn mirror(origin: Point, direction: Vector) -> Self {
+        let direction = direction.normalize();
+        let (xx, yy, xy) = (direction.x * direction.x, direction.y * direction.y, direction.x * direction.y);
+
+        Self::translation(-origin.x, -origin.y)
+            .then(&Self::new(xx - yy, 2.0 * xy, 2.0 * xy, yy - xx, 0.0, 0.0))
+            .then_translate(origin.to_vector())
+    }
+
+    fn flips_winding(&self) -> bool {
+        self.determinant() < 0.0
+    }
+
+    fn is_similarity(&self) -> bool {
+        let (x, y) = (Vector::new(self.m11, self.m12), Vector::new(self.m21, self.m22));
+        let tolerance = x.square_length().max(y.square_length()) * 1e-4;
+
+        (x.square_length() - y.square_length()).abs() <= tolerance && x.dot(y).abs() <= tolerance
+    }
+}
@@ -1,27 +1,46 @@
 #![allow(clippy::cast_precision_loss)]
 #![doc = include_str!("../README.md")]
 
+pub mod android;
+pub mod animation;
+mod batch;
+mod blend;
 mod cubic;
+mod dash;
+pub mod easing;
+#[cfg(feature = "egui")] pub mod egui;
 mod feature;
 mod feature_mapper;
+mod fit;
+#[cfg(feature = "geo")] mod geo;
 pub mod geometry;
 mod mapper;
 mod measured_polygon;
 mod measurer;
 mod morph;
+mod parametric;
 pub mod path;
 mod polygon_builder;
+#[cfg(feature = "tiny-skia-render")] pub mod render;
 mod rounded_polygon;
 pub mod shapes;
+pub mod stagger;
 pub(crate) mod util;
+pub mod warp;
 
+#[cfg(feature = "geo")] pub use self::geo::VertexRounding;
 pub use self::{
+    batch::{BatchFrame, MorphBatch},
+    blend::MultiMorph,
     cubic::Cubic,
+    dash::Dasher,
     feature::{Feature, FeatureType},
+    fit::{Alignment, Fit, FitMode},
     mapper::DoubleMapper,
-    measured_polygon::{MeasuredPolygon, ProgressableFeature},
-    measurer::Measurer,
-    morph::Morph,
+    measured_polygon::{MeasuredPolygon, OutlineSample, ProgressableFeature},
+    measurer::{ArcLengthMeasurer, LengthMeasurer, Measurer},
+    morph::{Morph, MorphCubics, MorphFrame},
+    parametric::ParametricMorph,
     polygon_builder::RoundedPolygonBuilder,
-    rounded_polygon::{CornerRounding, RoundedPoint, RoundedPolygon},
+    rounded_polygon::{CornerRounding, PolygonSource, RoundedPoint, RoundedPolygon},
 };
@@ -1,4 +1,8 @@
-use crate::{Cubic, Feature, Measurer, RoundedPolygon, geometry::DISTANCE_EPSILON, util::positive_modulo};
+use crate::{
+    Cubic, Feature, Measurer, RoundedPolygon,
+    geometry::{DISTANCE_EPSILON, Point, Vector},
+    util::{positive_modulo, progress_distance},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct MeasuredCubic {
@@ -44,6 +48,20 @@ impl MeasuredCubic {
     }
 }
 
+/// Describes the outline of a shape at a single outline progress value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutlineSample {
+    pub point: Point,
+    /// Unit vector in the direction of the outline.
+    pub tangent: Vector,
+    /// Unit vector perpendicular to the outline, pointing outwards for shapes
+    /// wound the same way as the ones created by [`RoundedPolygon`].
+    pub normal: Vector,
+    /// Signed curvature of the outline (the reciprocal of the radius of the
+    /// osculating circle), positive where the outline is convex.
+    pub curvature: f32,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ProgressableFeature {
     pub progress: f32,
@@ -108,6 +126,32 @@ impl<T: Measurer> MeasuredPolygon<T> {
             }
         }
 
+        let outline_progress = Self::outline_progress(&measurer, &cubics);
+        let mut features = Vec::new();
+
+        for (feature, ix) in feature_to_cubic {
+            features.push(ProgressableFeature {
+                progress: positive_modulo(outline_progress[ix].midpoint(outline_progress[ix + 1]), 1.0),
+                feature: feature.clone(),
+            });
+        }
+
+        Self::new(measurer, features, &cubics, &outline_progress)
+    }
+
+    /// Measures an outline made of `cubics`, e.g. a transition state of a
+    /// [`Morph`](crate::Morph), which has no features.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `cubics` is empty.
+    pub fn measure_cubics(measurer: T, cubics: &[Cubic]) -> Self {
+        let outline_progress = Self::outline_progress(&measurer, cubics);
+
+        Self::new(measurer, Vec::new(), cubics, &outline_progress)
+    }
+
+    fn outline_progress(measurer: &T, cubics: &[Cubic]) -> Vec<f32> {
         let measure_results = cubics.iter().fold(vec![0.0], |mut measure, cubic| {
             measure.push(measure[measure.len() - 1] + measurer.measure_cubic(cubic));
 
@@ -116,22 +160,214 @@ impl<T: Measurer> MeasuredPolygon<T> {
 
         let total_measure = measure_results[measure_results.len() - 1];
 
-        let mut outline_progress = Vec::with_capacity(measure_results.len());
+        measure_results.into_iter().map(|measure| measure / total_measure).collect()
+    }
+
+    /// Returns the measured cubic containing the given outline `progress`
+    /// (wrapped into `0.0..1.0`), along with the parameter `t` of that cubic at
+    /// which the progress is reached.
+    fn locate(&self, progress: f32) -> (&MeasuredCubic, f32) {
+        let progress = positive_modulo(progress, 1.0);
+        let index = self
+            .cubics
+            .partition_point(|cubic| cubic.end_outline_progress < progress)
+            .min(self.cubics.len() - 1);
+        let cubic = &self.cubics[index];
+        let relative_progress = (progress - cubic.start_outline_progress) / (cubic.end_outline_progress - cubic.start_outline_progress);
+
+        (cubic, self.measurer.find_cubic_cut_point(&cubic.cubic, relative_progress * cubic.measured_size))
+    }
+
+    /// Returns the point on the outline at the given outline `progress`, which
+    /// wraps around outside of `0.0..1.0`.
+    pub fn point_at(&self, progress: f32) -> Point {
+        let (cubic, t) = self.locate(progress);
+
+        cubic.cubic.point_on_curve(t)
+    }
 
-        for measure in measure_results {
-            outline_progress.push(measure / total_measure);
+    /// Returns the point, direction and curvature of the outline at the given
+    /// outline `progress`, which wraps around outside of `0.0..1.0`.
+    pub fn sample(&self, progress: f32) -> OutlineSample {
+        let (cubic, t) = self.locate(progress);
+        let cubic = &cubic.cubic;
+        let mut velocity = cubic.derivative(t);
+
+        // Control points may coincide with anchor points, in which case the direction
+        // is taken from just inside the curve
+        if velocity.square_length() < DISTANCE_EPSILON * DISTANCE_EPSILON {
+            velocity = cubic.derivative(t.clamp(1e-3, 1.0 - 1e-3));
         }
 
-        let mut features = Vec::new();
+        if velocity.square_length() < DISTANCE_EPSILON * DISTANCE_EPSILON {
+            velocity = cubic.anchor1() - cubic.anchor0();
+        }
 
-        for (feature, ix) in feature_to_cubic {
-            features.push(ProgressableFeature {
-                progress: positive_modulo(outline_progress[ix].midpoint(outline_progress[ix + 1]), 1.0),
-                feature: feature.clone(),
-            });
+        let speed = velocity.length();
+        let tangent = if speed > 0.0 { velocity / speed } else { Vector::zero() };
+
+        OutlineSample {
+            point: cubic.point_on_curve(t),
+            tangent,
+            normal: Vector::new(tangent.y, -tangent.x),
+            curvature: if speed > DISTANCE_EPSILON {
+                velocity.cross(cubic.second_derivative(t)) / (speed * speed * speed)
+            } else {
+                0.0
+            },
+        }
+    }
+
+    /// Returns `n` samples of the outline equally spaced by outline progress,
+    /// starting at `0.0`.
+    ///
+    /// If `snap_to_corners` is `true`, the closest sample to the middle of each
+    /// corner feature is moved exactly onto it, so corners are not cut off, and
+    /// the samples in between are spaced equally again. Only the outlines of
+    /// [`RoundedPolygon`]s have features, see
+    /// [`MeasuredPolygon::measure_polygon`].
+    pub fn resample(&self, n: usize, snap_to_corners: bool) -> Vec<OutlineSample> {
+        let uniform = |index: usize| index as f32 / n as f32;
+        let mut anchors = <Vec<(usize, f32)>>::new();
+
+        if snap_to_corners && n > 0 {
+            for feature in self.features.iter().filter(|feature| feature.feature.is_corner()) {
+                let index = (0..n)
+                    .min_by(|&a, &b| progress_distance(uniform(a), feature.progress).total_cmp(&progress_distance(uniform(b), feature.progress)))
+                    .unwrap_or_default();
+
+                // Corners closer to each other than the sample spacing keep only one sample
+                if anchors.iter().all(|&(other, _)| other != index) {
+                    anchors.push((index, feature.progress));
+                }
+            }
+
+            anchors.sort_by_key(|&(index, _)| index);
         }
 
-        Self::new(measurer, features, &cubics, &outline_progress)
+        let mut progress = (0..n).map(uniform).collect::<Vec<_>>();
+
+        // Distribute the samples between each pair of consecutive anchors
+        for (i, &(start_index, start_progress)) in anchors.iter().enumerate() {
+            let (end_index, end_progress) = anchors.get(i + 1).copied().unwrap_or((anchors[0].0 + n, anchors[0].1));
+            let span = match positive_modulo(end_progress - start_progress, 1.0) {
+                span if span > 0.0 => span,
+                _ => 1.0,
+            };
+
+            for index in start_index..end_index {
+                let fraction = (index - start_index) as f32 / (end_index - start_index) as f32;
+
+                progress[index % n] = positive_modulo(span.mul_add(fraction, start_progress), 1.0);
+            }
+        }
+
+        progress.into_iter().map(|progress| self.sample(progress)).collect()
+    }
+
+    /// Returns the outline progress of the point on the outline closest to
+    /// `point`.
+    pub fn progress_of_closest(&self, point: Point) -> f32 {
+        const SAMPLES: usize = 16;
+
+        let distance = |cubic: &Cubic, t: f32| (cubic.point_on_curve(t) - point).square_length();
+        let closest = |index: usize| {
+            let measured = &self.cubics[index];
+
+            // Find a rough estimate by sampling, then refine it with a ternary search
+            let step = 1.0 / SAMPLES as f32;
+            let t = (0..=SAMPLES)
+                .map(|i| i as f32 * step)
+                .min_by(|&a, &b| distance(&measured.cubic, a).total_cmp(&distance(&measured.cubic, b)))
+                .unwrap_or_default();
+            let (mut low, mut high) = ((t - step).max(0.0), (t + step).min(1.0));
+
+            for _ in 0..24 {
+                let a = low + (high - low) / 3.0;
+                let b = high - (high - low) / 3.0;
+
+                if distance(&measured.cubic, a) < distance(&measured.cubic, b) {
+                    high = b;
+                } else {
+                    low = a;
+                }
+            }
+
+            let t = low.midpoint(high);
+
+            (index, t, distance(&measured.cubic, t))
+        };
+        // Measured polygons always have at least one cubic
+        let (index, t, _) = (1..self.cubics.len())
+            .map(closest)
+            .fold(closest(0), |best, candidate| if candidate.2 < best.2 { candidate } else { best });
+        let cubic = &self.cubics[index];
+
+        // Invert the cut point search of the measurer, so that the result is consistent
+        // with `point_at`
+        let (mut low, mut high) = (0.0f32, 1.0f32);
+
+        for _ in 0..24 {
+            let middle = low.midpoint(high);
+
+            if self.measurer.find_cubic_cut_point(&cubic.cubic, middle * cubic.measured_size) < t {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        positive_modulo(
+            (cubic.end_outline_progress - cubic.start_outline_progress).mul_add(low.midpoint(high), cubic.start_outline_progress),
+            1.0,
+        )
+    }
+
+    /// Returns the open part of the outline between the `start` and `end`
+    /// outline progress values, both shifted by `offset`, like the "trim path"
+    /// effect of animation tools.
+    ///
+    /// `start` and `end` are expected to be in `0.0..=1.0` and are swapped if
+    /// `start` is greater. The trimmed part wraps across `0.0`, so animating
+    /// `offset` makes it travel around the outline. The result can be added to
+    /// a path with [`add_cubics`](crate::path::add_cubics).
+    pub fn trimmed(&self, start: f32, end: f32, offset: f32) -> Vec<Cubic> {
+        let length = (end - start).abs().min(1.0);
+        let start = positive_modulo(start.min(end) + offset, 1.0);
+        let end = start + length;
+        let mut cubics = Vec::new();
+
+        if length < DISTANCE_EPSILON {
+            return cubics;
+        }
+
+        // The trimmed part may wrap across 0, so the outline is traversed twice, the
+        // second time shifted by a full turn
+        for lap in [0.0, 1.0] {
+            for &cubic in &self.cubics {
+                let from = start.max(cubic.start_outline_progress + lap);
+                let to = end.min(cubic.end_outline_progress + lap);
+
+                if to - from <= f32::EPSILON {
+                    continue;
+                }
+
+                let cubic = if from > cubic.start_outline_progress + lap {
+                    cubic.cut_at_progress(&self.measurer, from - lap).1
+                } else {
+                    cubic
+                };
+                let cubic = if to < cubic.end_outline_progress + lap {
+                    cubic.cut_at_progress(&self.measurer, to - lap).0
+                } else {
+                    cubic
+                };
+
+                cubics.push(cubic.cubic);
+            }
+        }
+
+        cubics
     }
 
     /// # Panics
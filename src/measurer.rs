@@ -12,7 +12,8 @@ pub trait Measurer {
     fn find_cubic_cut_point(&self, c: &Cubic, m: f32) -> f32;
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Measures cubics by their approximate arc length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct LengthMeasurer;
 
 impl LengthMeasurer {
@@ -51,3 +52,75 @@ impl Measurer for LengthMeasurer {
         Self::closest_progress_to(c, m).0
     }
 }
+
+/// Measures cubics by their arc length, computed by adaptive subdivision until
+/// the error is within a tolerance.
+///
+/// It's slower than [`LengthMeasurer`], which approximates cubics with 3
+/// chords and underestimates the length of strongly curved ones, but it's
+/// accurate enough for laying out lengths along outlines, e.g. with
+/// [`Dasher`](crate::Dasher).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArcLengthMeasurer {
+    tolerance: f32,
+}
+
+impl ArcLengthMeasurer {
+    /// The number of bisection steps when finding cut points.
+    const CUT_ITERATIONS: usize = 24;
+    /// The maximum number of times a cubic is halved when measuring it.
+    const MAX_DEPTH: usize = 16;
+
+    /// Returns a measurer with lengths accurate within about `tolerance`.
+    pub const fn new(tolerance: f32) -> Self {
+        Self { tolerance }
+    }
+
+    pub const fn tolerance(&self) -> f32 {
+        self.tolerance
+    }
+
+    fn length(cubic: Cubic, tolerance: f32, depth: usize) -> f32 {
+        let chord = (cubic.anchor1() - cubic.anchor0()).length();
+        let polygon =
+            (cubic.control0() - cubic.anchor0()).length() + (cubic.control1() - cubic.control0()).length() + (cubic.anchor1() - cubic.control1()).length();
+
+        // The arc length is between the chord and control polygon lengths, and
+        // their weighted average converges quickly as the cubic is subdivided
+        if polygon - chord <= tolerance || depth >= Self::MAX_DEPTH {
+            return polygon.mul_add(2.0, chord) / 3.0;
+        }
+
+        let (first, second) = cubic.split(0.5);
+
+        Self::length(first, tolerance / 2.0, depth + 1) + Self::length(second, tolerance / 2.0, depth + 1)
+    }
+}
+
+impl Default for ArcLengthMeasurer {
+    fn default() -> Self {
+        Self::new(1e-4)
+    }
+}
+
+impl Measurer for ArcLengthMeasurer {
+    fn measure_cubic(&self, c: &Cubic) -> f32 {
+        Self::length(*c, self.tolerance, 0)
+    }
+
+    fn find_cubic_cut_point(&self, c: &Cubic, m: f32) -> f32 {
+        let (mut low, mut high) = (0.0f32, 1.0f32);
+
+        for _ in 0..Self::CUT_ITERATIONS {
+            let middle = low.midpoint(high);
+
+            if self.measure_cubic(&c.split(middle).0) < m {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        low.midpoint(high)
+    }
+}
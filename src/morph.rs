@@ -1,11 +1,13 @@
-use core::f32;
+use core::{f32, ops::RangeInclusive, slice};
 
 use crate::{
-    Cubic, DoubleMapper, MeasuredPolygon, RoundedPolygon,
-    geometry::ANGLE_EPSILON,
-    measurer::LengthMeasurer,
-    path::{PathBuilder, add_cubics},
-    util::positive_modulo,
+    Cubic, DoubleMapper, Fit, MeasuredPolygon, OutlineSample, RoundedPolygon,
+    easing::{EasedMorph, Easing},
+    geometry::{ANGLE_EPSILON, Aabb, Angle, Matrix2, Point, PointTransformer, TransformExt, Vector},
+    measurer::{ArcLengthMeasurer, LengthMeasurer, Measurer},
+    path::{OptionCubics, PathBuilder, PathEvents, PathOptions, add_cubics, add_events},
+    stagger::{MorphSegment, Stagger},
+    util::{centroid, flatten, positive_modulo},
 };
 
 /// A structure designed to obtain transition cubics between the start and end
@@ -15,6 +17,7 @@ pub struct Morph {
     start: RoundedPolygon,
     end: RoundedPolygon,
     r#match: Vec<(Cubic, Cubic)>,
+    segments: Vec<MorphSegment>,
 }
 
 impl Morph {
@@ -26,49 +29,233 @@ impl Morph {
     /// May panic if not all cubics of both polygons have been matched.
     pub fn new(start: RoundedPolygon, end: RoundedPolygon) -> Self {
         let r#match = Self::match_morph(&start, &end);
+        let segments = Self::describe_segments(&start, &r#match);
 
-        Self { start, end, r#match }
+        Self { start, end, r#match, segments }
+    }
+
+    /// Returns descriptions of the matched segments of this morph, in the same
+    /// order as the cubics returned by [`Morph::as_cubics`].
+    pub fn segments(&self) -> &[MorphSegment] {
+        &self.segments
+    }
+
+    /// Returns the transition state where each segment is at the progress
+    /// returned by `stagger`, represented as a list of [`Cubic`]s.
+    ///
+    /// Anchor points shared by neighbouring segments use the average of their
+    /// progress values, so the outline stays continuous and closed even if
+    /// segments are at very different stages of the transition.
+    pub fn as_cubics_staggered<S: Stagger>(&self, progress: f32, stagger: &S) -> Vec<Cubic> {
+        let n = self.r#match.len();
+        let progresses = self.segments.iter().map(|segment| stagger.progress(progress, segment)).collect::<Vec<_>>();
+        // The anchor point between a segment and the next one
+        let joint = |i: usize| {
+            let (start, end) = self.r#match[i];
+
+            start.anchor1().lerp(end.anchor1(), progresses[i].midpoint(progresses[(i + 1) % n]))
+        };
+
+        (0..n)
+            .map(|i| {
+                let (start, end) = self.r#match[i];
+
+                Cubic::new(
+                    joint((i + n - 1) % n),
+                    start.control0().lerp(end.control0(), progresses[i]),
+                    start.control1().lerp(end.control1(), progresses[i]),
+                    joint(i),
+                )
+            })
+            .collect()
+    }
+
+    /// Adds a staggered transition state (see [`Morph::as_cubics_staggered`])
+    /// to the `builder`.
+    pub fn add_staggered_to<T: PathBuilder, S: Stagger>(&self, progress: f32, stagger: &S, builder: &mut T, repeat_path: bool, close_path: bool) {
+        add_cubics(builder, repeat_path, close_path, self.as_cubics_staggered(progress, stagger));
     }
 
     /// Returns the transition state between the start and end polygons at a
     /// given `progress` value represented as a list of [`Cubic`]s.
+    ///
+    /// Progress values outside of `0.0..=1.0` linearly extrapolate the matched
+    /// control points, so an overshooting animation (e.g. one driven by a
+    /// [`Spring`](crate::animation::Spring)) continues past the end shape in
+    /// the same direction and the outline stays closed. Large overshoots may
+    /// produce self-intersecting outlines.
     pub fn as_cubics(&self, progress: f32) -> Vec<Cubic> {
-        let mut cubics = Vec::new();
+        self.cubics_at(progress).collect()
+    }
 
+    /// Writes the transition state between the start and end polygons at a
+    /// given `progress` value into `cubics`, reusing its allocation.
+    ///
+    /// Any previous contents of `cubics` are removed.
+    pub fn as_cubics_into(&self, progress: f32, cubics: &mut Vec<Cubic>) {
+        cubics.clear();
+        cubics.extend(self.cubics_at(progress));
+    }
+
+    /// Returns an iterator that lazily yields the transition state between the
+    /// start and end polygons at a given `progress` value.
+    ///
+    /// See [`Morph::as_cubics`] for the handling of `progress` outside of
+    /// `0.0..=1.0`.
+    pub fn cubics_at(&self, progress: f32) -> MorphCubics<'_> {
         // The first/last mechanism here ensures that the final anchor point in the
         // shape exactly matches the first anchor point. There can be rendering
         // artifacts introduced by those points being slightly off, even by much
         // less than a pixel
-        let mut first_cubic: Option<Cubic> = None;
-        let mut last_cubic: Option<Cubic> = None;
-
-        for i in 0..self.r#match.len() {
-            let cubic = Cubic::from_fn(|it| self.r#match[i].0.points[it].lerp(self.r#match[i].1.points[it], progress));
+        let first_anchor = self
+            .r#match
+            .first()
+            .map_or_else(Point::zero, |(start, end)| start.anchor0().lerp(end.anchor0(), progress));
+
+        MorphCubics {
+            pairs: self.r#match.iter(),
+            progress,
+            first_anchor,
+        }
+    }
 
-            if first_cubic.is_none() {
-                first_cubic.replace(cubic);
-            }
+    /// Returns a lightweight snapshot of the transition state at the given
+    /// `progress`, which borrows this morph instead of computing the cubics.
+    pub const fn frame(&self, progress: f32) -> MorphFrame<'_> {
+        MorphFrame { morph: self, progress }
+    }
 
-            if let Some(cubic) = last_cubic {
-                cubics.push(cubic);
-            }
+    /// Returns an axis-aligned bounding box describing bounds of the
+    /// transition state at the given `progress`.
+    ///
+    /// If `approximate` is `true`, a fast but sometimes inaccurate algorithm is
+    /// used to calculate AABB of cubics, see [`Cubic::aabb`].
+    pub fn aabb(&self, progress: f32, approximate: bool) -> Aabb {
+        self.cubics_at(progress)
+            .fold(Aabb::new(Point::splat(f32::MAX), Point::splat(f32::MIN)), |aabb, cubic| {
+                let cubic_aabb = cubic.aabb(approximate);
+
+                Aabb {
+                    min: aabb.min.min(cubic_aabb.min),
+                    max: aabb.max.max(cubic_aabb.max),
+                }
+            })
+    }
 
-            last_cubic.replace(cubic);
+    /// Returns an axis-aligned bounding box containing every transition state
+    /// for progress values in `progress_range`, which may extend beyond
+    /// `0.0..=1.0` to account for overshooting animations.
+    ///
+    /// Every control point moves linearly with progress, so for any fixed
+    /// curve parameter each coordinate of a transition state is a linear
+    /// function of progress and reaches its extremes at the ends of the range.
+    /// The bounds over the whole range are therefore the union of the bounds
+    /// of the two boundary states: exact if `approximate` is `false`, and
+    /// conservative (based on control points) otherwise.
+    pub fn bounds(&self, progress_range: RangeInclusive<f32>, approximate: bool) -> Aabb {
+        let start = self.aabb(*progress_range.start(), approximate);
+        let end = self.aabb(*progress_range.end(), approximate);
+
+        Aabb {
+            min: start.min.min(end.min),
+            max: start.max.max(end.max),
         }
+    }
 
-        #[allow(clippy::collapsible_if)] // For MSRV compability
-        if let Some(last_cubic) = last_cubic {
-            if let Some(first_cubic) = first_cubic {
-                cubics.push(Cubic::new(
-                    last_cubic.anchor0(),
-                    last_cubic.control0(),
-                    last_cubic.control1(),
-                    first_cubic.anchor0(),
-                ));
-            }
+    /// Returns a [`Morph`] with both shapes and all matched cubics transformed
+    /// using the provided `f`, which implements [`PointTransformer`] trait.
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn transformed<T: PointTransformer>(self, f: T) -> Self {
+        let start = self.start.transformed(|point| f.transform(point));
+        let end = self.end.transformed(|point| f.transform(point));
+        let r#match = self
+            .r#match
+            .into_iter()
+            .map(|(start, end)| (start.transformed(&f), end.transformed(&f)))
+            .collect::<Vec<_>>();
+        let segments = Self::describe_segments(&start, &r#match);
+
+        Self { start, end, r#match, segments }
+    }
+
+    /// Returns a [`Morph`] transformed using the provided affine `matrix`.
+    ///
+    /// If the transformation flips winding (e.g. a mirror), the outlines are
+    /// also reversed, see [`RoundedPolygon::transformed_affine`].
+    #[must_use]
+    pub fn transformed_affine(self, matrix: &Matrix2) -> Self {
+        if !matrix.flips_winding() {
+            return self.transformed(*matrix);
         }
 
-        cubics
+        let start = self.start.transformed_affine(matrix);
+        let end = self.end.transformed_affine(matrix);
+        let r#match = self
+            .r#match
+            .into_iter()
+            .rev()
+            .map(|(start, end)| (start.transformed(matrix).reversed(), end.transformed(matrix).reversed()))
+            .collect::<Vec<_>>();
+        let segments = Self::describe_segments(&start, &r#match);
+
+        Self { start, end, r#match, segments }
+    }
+
+    /// Returns a [`Morph`] rotated by `angle` around `center`.
+    #[must_use]
+    pub fn rotated(self, angle: Angle, center: Point) -> Self {
+        self.transformed_affine(&Matrix2::rotation_about(angle, center))
+    }
+
+    /// Returns a [`Morph`] scaled by `x` and `y` factors relative to `center`.
+    #[must_use]
+    pub fn scaled(self, x: f32, y: f32, center: Point) -> Self {
+        self.transformed_affine(&Matrix2::scale_about(x, y, center))
+    }
+
+    /// Returns a [`Morph`] reflected across the line going through `origin` in
+    /// the given `direction`.
+    #[must_use]
+    pub fn mirrored(self, origin: Point, direction: Vector) -> Self {
+        self.transformed_affine(&Matrix2::mirror(origin, direction))
+    }
+
+    /// Moves and resizes [`Morph`] to fit into `rect` as described by `fit`.
+    ///
+    /// The bounds of the whole transition (see [`Morph::bounds`]) are fitted,
+    /// so the same transformation is applied at every progress value and the
+    /// animation does not jump. Optical centering uses the average of the
+    /// centroids of the start and end states.
+    #[must_use]
+    pub fn fit_into(self, rect: Aabb, fit: Fit) -> Self {
+        let centroid = centroid(self.cubics_at(0.0))
+            .zip(centroid(self.cubics_at(1.0)))
+            .map(|(start, end)| start.lerp(end, 0.5));
+        let transform = fit.transform(self.bounds(0.0..=1.0, !fit.exact_bounds), centroid, rect);
+
+        self.transformed(transform)
+    }
+
+    /// Returns the outline of the transition state at the given `progress`
+    /// measured with [`LengthMeasurer`], which approximates arc lengths with
+    /// chords, and allows sampling it at any outline progress.
+    pub fn measured(&self, progress: f32) -> MeasuredPolygon<LengthMeasurer> {
+        MeasuredPolygon::measure_cubics(LengthMeasurer, &self.as_cubics(progress))
+    }
+
+    /// Returns `n` samples of the outline of the transition state at the given
+    /// `progress`, equally spaced by arc length. The outline is measured with
+    /// [`ArcLengthMeasurer`].
+    pub fn resample(&self, progress: f32, n: usize) -> Vec<OutlineSample> {
+        MeasuredPolygon::measure_cubics(ArcLengthMeasurer::default(), &self.as_cubics(progress)).resample(n, false)
+    }
+
+    /// Returns the open part of the outline of the transition state at the
+    /// given `progress` between the `start` and `end` outline progress values,
+    /// both shifted by `offset`, see [`MeasuredPolygon::trimmed`].
+    pub fn trimmed(&self, progress: f32, start: f32, end: f32, offset: f32) -> Vec<Cubic> {
+        self.measured(progress).trimmed(start, end, offset)
     }
 
     /// Returns a path with a drawn transition state (based on the provided
@@ -82,12 +269,68 @@ impl Morph {
         path.build()
     }
 
+    /// Returns an iterator over the [`PathEvent`](crate::path::PathEvent)s
+    /// drawing the transition state at the given `progress`.
+    pub fn path_events(&self, progress: f32) -> PathEvents<MorphCubics<'_>> {
+        PathEvents::new(self.cubics_at(progress))
+    }
+
+    /// Returns an iterator over the [`PathEvent`](crate::path::PathEvent)s
+    /// drawing the transition state at the given `progress` as described by
+    /// the `options`.
+    pub fn path_events_with(&self, progress: f32, options: PathOptions) -> PathEvents<OptionCubics<MorphCubics<'_>>> {
+        PathEvents::with_options(self.cubics_at(progress), options)
+    }
+
+    /// Returns a path of the transition state at the given `progress` drawn as
+    /// described by the `options`. Path is created using the provided `T`,
+    /// which should implement `PathBuilder` and `Default` traits.
+    pub fn as_path_with<T: PathBuilder + Default>(&self, progress: f32, options: PathOptions) -> T::Path {
+        let mut path = T::default();
+
+        add_events(&mut path, self.path_events_with(progress, options));
+
+        path.build()
+    }
+
     /// Adds a transition state (based on the provided `progress`) to the
     /// `builder`.
     pub fn add_to<T: PathBuilder>(&self, progress: f32, builder: &mut T, repeat_path: bool, close_path: bool) {
-        let cubics = self.as_cubics(progress);
+        add_cubics(builder, repeat_path, close_path, self.cubics_at(progress));
+    }
+
+    /// Returns an [`EasedMorph`] that applies `easing` to the progress of this
+    /// morph.
+    pub const fn eased<E: Easing>(self, easing: E) -> EasedMorph<E> {
+        EasedMorph::new(self, easing)
+    }
+
+    /// Returns the matched pairs of start and end [`Cubic`]s.
+    pub(crate) fn pairs(&self) -> &[(Cubic, Cubic)] {
+        &self.r#match
+    }
 
-        add_cubics(builder, repeat_path, close_path, &cubics);
+    fn describe_segments(start: &RoundedPolygon, r#match: &[(Cubic, Cubic)]) -> Vec<MorphSegment> {
+        let measures = r#match.iter().map(|(cubic, _)| LengthMeasurer.measure_cubic(cubic)).collect::<Vec<_>>();
+        let total_measure = measures.iter().sum::<f32>().max(f32::EPSILON);
+        let mut measure = 0.0;
+
+        r#match
+            .iter()
+            .zip(measures)
+            .enumerate()
+            .map(|(index, ((cubic, _), size))| {
+                let middle = cubic.point_on_curve(0.5) - start.center;
+
+                measure += size;
+
+                MorphSegment {
+                    index,
+                    outline_progress: positive_modulo((measure - size / 2.0) / total_measure, 1.0),
+                    angle: positive_modulo(middle.y.atan2(middle.x), f32::consts::TAU),
+                }
+            })
+            .collect()
     }
 
     fn match_morph(p1: &RoundedPolygon, p2: &RoundedPolygon) -> Vec<(Cubic, Cubic)> {
@@ -179,3 +422,78 @@ impl Morph {
         ret
     }
 }
+
+/// A transition state of a [`Morph`] at a fixed progress.
+///
+/// This struct is created by [`Morph::frame`]. With the `kurbo` feature, it
+/// implements `kurbo::Shape`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MorphFrame<'a> {
+    morph: &'a Morph,
+    progress: f32,
+}
+
+impl<'a> MorphFrame<'a> {
+    pub const fn morph(&self) -> &'a Morph {
+        self.morph
+    }
+
+    pub const fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    /// Returns an iterator over the cubics of this transition state, see
+    /// [`Morph::cubics_at`].
+    pub fn cubics(&self) -> MorphCubics<'a> {
+        self.morph.cubics_at(self.progress)
+    }
+
+    /// Returns the center of this transition state, interpolated between the
+    /// centers of the start and end polygons.
+    pub fn center(&self) -> Point {
+        self.morph.start.center.lerp(self.morph.end.center, self.progress)
+    }
+
+    /// Returns the outline of this transition state approximated with line
+    /// segments, see [`RoundedPolygon::flattened`].
+    pub fn flattened(&self, tolerance: f32) -> Vec<Point> {
+        flatten(self.cubics(), tolerance)
+    }
+
+    /// Returns an iterator over the path events drawing this transition state,
+    /// see [`Morph::path_events`].
+    pub fn path_events(&self) -> PathEvents<MorphCubics<'a>> {
+        self.morph.path_events(self.progress)
+    }
+}
+
+/// An iterator over the [`Cubic`]s of a [`Morph`] transition state.
+///
+/// This struct is created by [`Morph::cubics_at`].
+#[derive(Debug, Clone)]
+pub struct MorphCubics<'a> {
+    pairs: slice::Iter<'a, (Cubic, Cubic)>,
+    progress: f32,
+    first_anchor: Point,
+}
+
+impl Iterator for MorphCubics<'_> {
+    type Item = Cubic;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.pairs.next()?;
+        let mut cubic = Cubic::from_fn(|it| start.points[it].lerp(end.points[it], self.progress));
+
+        if self.pairs.len() == 0 {
+            cubic.points[3] = self.first_anchor;
+        }
+
+        Some(cubic)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pairs.size_hint()
+    }
+}
+
+impl ExactSizeIterator for MorphCubics<'_> {}
@@ -0,0 +1,139 @@
+use crate::{
+    Cubic, RoundedPolygon,
+    geometry::Point,
+    path::{PathBuilder, add_cubics},
+    rounded_polygon::{CornerRounding, PolygonSource},
+};
+
+/// A structure designed to morph between two topologically identical
+/// [`RoundedPolygon`]s by interpolating their construction parameters.
+///
+/// Unlike [`Morph`], which interpolates the output cubics, vertex positions and
+/// corner roundings are interpolated and the polygon is rebuilt with
+/// [`RoundedPolygon::from_vertices`] for every frame, so corners stay truly
+/// round throughout the transition.
+///
+/// [`Morph`]: crate::Morph
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParametricMorph {
+    start: RoundedPolygon,
+    end: RoundedPolygon,
+    /// Source of the start shape.
+    from: PolygonSource,
+    /// Source of the end shape, rotated so its vertices correspond to the ones
+    /// of the start shape.
+    to: PolygonSource,
+}
+
+impl ParametricMorph {
+    /// # Panics
+    ///
+    /// Panics if the shapes are not compatible, see
+    /// [`ParametricMorph::is_compatible`].
+    pub fn new(start: RoundedPolygon, end: RoundedPolygon) -> Self {
+        let (from, to) = Self::compatible_sources(&start, &end).expect("Parametric morph needs shapes created from the same number of vertices");
+        let to = Self::match_vertices(from, to);
+        let from = from.clone();
+
+        Self { start, end, from, to }
+    }
+
+    /// Returns `true` if both shapes remember their construction source (see
+    /// [`RoundedPolygon::source`]) and were created from the same number of
+    /// vertices.
+    pub fn is_compatible(start: &RoundedPolygon, end: &RoundedPolygon) -> bool {
+        Self::compatible_sources(start, end).is_some()
+    }
+
+    fn compatible_sources<'a>(start: &'a RoundedPolygon, end: &'a RoundedPolygon) -> Option<(&'a PolygonSource, &'a PolygonSource)> {
+        start.source().zip(end.source()).filter(|(from, to)| from.vertices.len() == to.vertices.len())
+    }
+
+    pub const fn start(&self) -> &RoundedPolygon {
+        &self.start
+    }
+
+    pub const fn end(&self) -> &RoundedPolygon {
+        &self.end
+    }
+
+    /// Returns the transition state at the given `progress` value, rebuilt
+    /// from the interpolated construction parameters.
+    ///
+    /// Progress values outside of `0.0..=1.0` extrapolate the parameters, with
+    /// rounding radii and smoothing kept in their valid ranges.
+    pub fn as_polygon(&self, progress: f32) -> RoundedPolygon {
+        let vertices = self
+            .from
+            .vertices
+            .iter()
+            .zip(&self.to.vertices)
+            .map(|(from, to)| from.lerp(*to, progress))
+            .collect::<Vec<_>>();
+        let roundings = self
+            .from
+            .roundings
+            .iter()
+            .zip(&self.to.roundings)
+            .map(|(from, to)| {
+                CornerRounding::smoothed(
+                    (to.radius - from.radius).mul_add(progress, from.radius).max(0.0),
+                    (to.smoothing - from.smoothing).mul_add(progress, from.smoothing).clamp(0.0, 1.0),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Centers computed from the vertices are computed again for the result
+        let center = if self.from.center.is_none() && self.to.center.is_none() {
+            Point::splat(f32::MIN)
+        } else {
+            self.from.center().lerp(self.to.center(), progress)
+        };
+
+        RoundedPolygon::from_vertices(&vertices, CornerRounding::UNROUNDED, &roundings, center)
+    }
+
+    /// Returns the transition state at the given `progress` value represented
+    /// as a list of [`Cubic`]s.
+    pub fn as_cubics(&self, progress: f32) -> Vec<Cubic> {
+        self.as_polygon(progress).cubics
+    }
+
+    /// Returns a path with a drawn transition state (based on `progress`). Path
+    /// is created using the provided `T`, which should implement `PathBuilder`
+    /// and `Default` traits.
+    pub fn as_path<T: PathBuilder + Default>(&self, progress: f32, repeat_path: bool, close_path: bool) -> T::Path {
+        let mut path = T::default();
+
+        self.add_to(progress, &mut path, repeat_path, close_path);
+
+        path.build()
+    }
+
+    /// Adds a transition state (based on `progress`) to the `builder`.
+    pub fn add_to<T: PathBuilder>(&self, progress: f32, builder: &mut T, repeat_path: bool, close_path: bool) {
+        add_cubics(builder, repeat_path, close_path, &self.as_polygon(progress).cubics);
+    }
+
+    /// Rotates the vertices of `to` so that they travel the shortest total
+    /// distance (relative to the centers) to the vertices of `from`.
+    fn match_vertices(from: &PolygonSource, to: &PolygonSource) -> PolygonSource {
+        let n = from.vertices.len();
+        let offset = (0..n)
+            .map(|offset| {
+                let distance = (0..n)
+                    .map(|i| ((from.vertices[i] - from.center()) - (to.vertices[(i + offset) % n] - to.center())).square_length())
+                    .sum::<f32>();
+
+                (offset, distance)
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map_or(0, |(offset, _)| offset);
+
+        PolygonSource {
+            vertices: (0..n).map(|i| to.vertices[(i + offset) % n]).collect(),
+            roundings: (0..n).map(|i| to.roundings[(i + offset) % n]).collect(),
+            center: to.center,
+        }
+    }
+}
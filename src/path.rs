@@ -1,4 +1,10 @@
-use crate::{Cubic, geometry::Point};
+use std::{borrow::Borrow, f32::consts::FRAC_1_SQRT_2, iter::Peekable, vec};
+
+use crate::{
+    Cubic, LengthMeasurer, MeasuredPolygon,
+    geometry::{DISTANCE_EPSILON, Point, PointTransformer},
+    util::positive_modulo,
+};
 
 /// A necessary trait for creating paths from polygons or adding polygons to
 /// existing paths.
@@ -13,10 +19,31 @@ pub trait PathBuilder {
     fn build(self) -> Self::Path;
 }
 
-pub fn add_cubics<T: PathBuilder>(builder: &mut T, repeat_path: bool, close_path: bool, cubics: &[Cubic]) {
+/// Adds the given [`Cubic`]s to the `builder`, starting a new contour at the
+/// first anchor point.
+///
+/// Cubics can be provided by anything that can be iterated more than once,
+/// such as slices or [`MorphCubics`](crate::MorphCubics), so no intermediate
+/// storage is required.
+///
+/// If `repeat_path` is `true`, the outline is added twice to the same contour,
+/// which lets path effects such as dashes wrap around the start point. If
+/// `close_path` is `true`, the contour is closed. These flags mirror the
+/// `toPath` methods of the Kotlin implementation and stay on every `as_path`
+/// and `add_to` method for existing callers. [`PathOptions`] don't repeat
+/// outlines and always close them, so `add_cubics(builder, false, true,
+/// cubics)` adds the same path as the default options.
+pub fn add_cubics<T, I>(builder: &mut T, repeat_path: bool, close_path: bool, cubics: I)
+where
+    T: PathBuilder,
+    I: IntoIterator<Item: Borrow<Cubic>, IntoIter: Clone>,
+{
+    let cubics = cubics.into_iter();
     let mut first = true;
 
-    for it in cubics {
+    for it in cubics.clone() {
+        let it = it.borrow();
+
         if first {
             builder.move_to(it.anchor0());
 
@@ -30,6 +57,8 @@ pub fn add_cubics<T: PathBuilder>(builder: &mut T, repeat_path: bool, close_path
         let mut first = true;
 
         for it in cubics {
+            let it = it.borrow();
+
             if first {
                 builder.line_to(it.anchor0());
 
@@ -45,6 +74,413 @@ pub fn add_cubics<T: PathBuilder>(builder: &mut T, repeat_path: bool, close_path
     }
 }
 
+/// Describes how the outline of a shape is turned into path events by
+/// [`RoundedPolygon::path_events_with`] and [`Morph::path_events_with`].
+///
+/// The default options emit the outline as is: starting at the first anchor
+/// point and made only of cubics, like [`add_cubics`] without repeating the
+/// outline.
+///
+/// [`RoundedPolygon::path_events_with`]: crate::RoundedPolygon::path_events_with
+/// [`Morph::path_events_with`]: crate::Morph::path_events_with
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PathOptions {
+    /// Outline progress at which the path starts, wrapped into `0.0..1.0`.
+    pub start: f32,
+    /// If `true`, the outline is emitted in the opposite winding direction.
+    pub reversed: bool,
+    /// If `true`, straight cubics are emitted as [`PathEvent::LineTo`].
+    pub straight_lines: bool,
+    /// If `true`, straight cubics of zero length are skipped.
+    pub skip_degenerate: bool,
+}
+
+impl PathOptions {
+    pub const fn new() -> Self {
+        Self {
+            start: 0.0,
+            reversed: false,
+            straight_lines: false,
+            skip_degenerate: false,
+        }
+    }
+
+    /// Makes the path start at the given outline progress.
+    #[must_use]
+    pub const fn starting_at(mut self, progress: f32) -> Self {
+        self.start = progress;
+
+        self
+    }
+
+    /// Makes the path wind in the opposite direction.
+    #[must_use]
+    pub const fn reversed(mut self) -> Self {
+        self.reversed = true;
+
+        self
+    }
+
+    /// Makes the path use lines for straight cubics.
+    #[must_use]
+    pub const fn straight_lines(mut self) -> Self {
+        self.straight_lines = true;
+
+        self
+    }
+
+    /// Makes the path skip degenerate cubics.
+    #[must_use]
+    pub const fn skip_degenerate(mut self) -> Self {
+        self.skip_degenerate = true;
+
+        self
+    }
+}
+
+/// A single drawing command of a path, see [`PathEvents`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathEvent {
+    MoveTo(Point),
+    LineTo(Point),
+    CubicTo { ctrl1: Point, ctrl2: Point, to: Point },
+    Close,
+}
+
+impl PathEvent {
+    /// Returns this event with all points transformed using the provided `f`.
+    #[must_use]
+    pub fn transformed<T: PointTransformer>(self, f: &T) -> Self {
+        match self {
+            Self::MoveTo(to) => Self::MoveTo(f.transform(to)),
+            Self::LineTo(to) => Self::LineTo(f.transform(to)),
+            Self::CubicTo { ctrl1, ctrl2, to } => Self::CubicTo {
+                ctrl1: f.transform(ctrl1),
+                ctrl2: f.transform(ctrl2),
+                to: f.transform(to),
+            },
+            Self::Close => Self::Close,
+        }
+    }
+
+    /// Passes this event to the corresponding method of the `builder`.
+    pub fn add_to<T: PathBuilder>(self, builder: &mut T) {
+        match self {
+            Self::MoveTo(to) => builder.move_to(to),
+            Self::LineTo(to) => builder.line_to(to),
+            Self::CubicTo { ctrl1, ctrl2, to } => builder.cubic_to(ctrl1, ctrl2, to),
+            Self::Close => builder.close(),
+        }
+    }
+}
+
+/// An iterator yielding the [`PathEvent`]s of a closed outline made of
+/// [`Cubic`]s.
+///
+/// The outline starts with a [`PathEvent::MoveTo`] to the first anchor point,
+/// followed by a [`PathEvent::CubicTo`] for each cubic and a final
+/// [`PathEvent::Close`].
+///
+/// This struct is created by [`RoundedPolygon::path_events`],
+/// [`Morph::path_events`] and their `_with` variants taking [`PathOptions`].
+/// Unlike [`PathBuilder`], it lets consumers pull the
+/// outline lazily, e.g. to transform it or to feed it to crates without a
+/// dedicated feature.
+///
+/// [`RoundedPolygon::path_events`]: crate::RoundedPolygon::path_events
+/// [`Morph::path_events`]: crate::Morph::path_events
+#[derive(Debug, Clone)]
+pub struct PathEvents<I: Iterator<Item = Cubic>> {
+    cubics: Peekable<I>,
+    options: PathOptions,
+    started: bool,
+    closed: bool,
+}
+
+impl<I: Iterator<Item = Cubic>> PathEvents<I> {
+    pub fn new<T: IntoIterator<IntoIter = I>>(cubics: T) -> Self {
+        Self {
+            cubics: cubics.into_iter().peekable(),
+            options: PathOptions::new(),
+            started: false,
+            closed: false,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Cubic>> PathEvents<OptionCubics<I>> {
+    /// Returns path events of the outline made of the given `cubics`, emitted
+    /// as described by the `options`.
+    ///
+    /// Cubics are only collected if the outline is reversed or starts past its
+    /// first anchor point, otherwise they are pulled lazily.
+    pub fn with_options<T: IntoIterator<IntoIter = I>>(cubics: T, options: PathOptions) -> Self {
+        let start = positive_modulo(options.start, 1.0);
+
+        if start == 0.0 && !options.reversed {
+            return Self {
+                options,
+                ..Self::new(OptionCubics(Arrangement::Lazy(cubics.into_iter())))
+            };
+        }
+
+        let mut cubics = cubics.into_iter().collect::<Vec<_>>();
+
+        if options.reversed {
+            cubics = cubics.into_iter().rev().map(Cubic::reversed).collect();
+        }
+
+        if start > 0.0 && !cubics.is_empty() {
+            cubics = MeasuredPolygon::measure_cubics(LengthMeasurer, &cubics).trimmed(0.0, 1.0, start);
+
+            // Cutting is approximate, so the outline is closed exactly where it starts
+            if let (Some(first), Some(last)) = (cubics.first().map(Cubic::anchor0), cubics.last_mut()) {
+                last.points[3] = first;
+            }
+        }
+
+        Self {
+            options,
+            ..Self::new(OptionCubics(Arrangement::Collected(cubics.into_iter())))
+        }
+    }
+}
+
+/// An iterator over the cubics of an outline arranged as described by
+/// [`PathOptions`], see [`PathEvents::with_options`].
+#[derive(Debug, Clone)]
+pub struct OptionCubics<I>(Arrangement<I>);
+
+#[derive(Debug, Clone)]
+enum Arrangement<I> {
+    Lazy(I),
+    Collected(vec::IntoIter<Cubic>),
+}
+
+impl<I: Iterator<Item = Cubic>> Iterator for OptionCubics<I> {
+    type Item = Cubic;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            Arrangement::Lazy(cubics) => cubics.next(),
+            Arrangement::Collected(cubics) => cubics.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.0 {
+            Arrangement::Lazy(cubics) => cubics.size_hint(),
+            Arrangement::Collected(cubics) => cubics.size_hint(),
+        }
+    }
+}
+
+impl<I: Iterator<Item = Cubic>> Iterator for PathEvents<I> {
+    type Item = PathEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+
+            // Empty outlines have no events at all
+            let Some(cubic) = self.cubics.peek() else {
+                self.closed = true;
+
+                return None;
+            };
+
+            return Some(PathEvent::MoveTo(cubic.anchor0()));
+        }
+
+        for cubic in self.cubics.by_ref() {
+            if self.options.skip_degenerate && cubic.zero_length() && cubic.is_straight() {
+                continue;
+            }
+
+            if self.options.straight_lines && cubic.is_straight() {
+                return Some(PathEvent::LineTo(cubic.anchor1()));
+            }
+
+            return Some(PathEvent::CubicTo {
+                ctrl1: cubic.control0(),
+                ctrl2: cubic.control1(),
+                to: cubic.anchor1(),
+            });
+        }
+
+        if self.closed {
+            None
+        } else {
+            self.closed = true;
+
+            Some(PathEvent::Close)
+        }
+    }
+}
+
+/// Passes all `events` to the `builder`.
+pub fn add_events<T: PathBuilder, I: IntoIterator<Item = PathEvent>>(builder: &mut T, events: I) {
+    for event in events {
+        event.add_to(builder);
+    }
+}
+
+/// Allows converting paths into contours made of [`Cubic`]s.
+///
+/// This is used by
+/// [`RoundedPolygon::from_path`](crate::RoundedPolygon::from_path)
+/// to create polygons from existing vector assets.
+pub trait ToCubics {
+    /// Returns the contours of the path, with lines, quadratic curves and
+    /// conics converted into cubics.
+    fn to_cubics(&self) -> Vec<Vec<Cubic>>;
+}
+
+/// A [`PathBuilder`] collecting contours as lists of [`Cubic`]s.
+///
+/// Besides lines and cubics, it accepts quadratic curves and conics, which
+/// are converted into cubics.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CubicsBuilder {
+    contours: Vec<Vec<Cubic>>,
+    current: Vec<Cubic>,
+    start: Point,
+    last: Point,
+}
+
+impl CubicsBuilder {
+    /// The maximum number of times a conic is halved when approximating it.
+    const MAX_CONIC_DEPTH: usize = 4;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn quad_to(&mut self, ctrl: Point, to: Point) {
+        let from = self.last;
+
+        self.cubic_to(from.lerp(ctrl, 2.0 / 3.0), to.lerp(ctrl, 2.0 / 3.0), to);
+    }
+
+    /// Adds a conic (rational quadratic curve) with the given `weight`, which
+    /// is approximated by one or more cubics. For example, a weight of
+    /// `sqrt(2) / 2` describes a quarter of a circle.
+    pub fn conic_to(&mut self, ctrl: Point, to: Point, weight: f32) {
+        self.add_conic(self.last, ctrl, to, weight, 0);
+    }
+
+    fn add_conic(&mut self, from: Point, ctrl: Point, to: Point, weight: f32, depth: usize) {
+        // A single cubic approximates arcs up to a quarter of a circle well, so
+        // wider conics are halved first
+        if weight < FRAC_1_SQRT_2 && depth < Self::MAX_CONIC_DEPTH {
+            let scale = (1.0 + weight).recip();
+            let ctrl0 = (from.to_vector() + ctrl.to_vector() * weight) * scale;
+            let ctrl1 = (ctrl.to_vector() * weight + to.to_vector()) * scale;
+            let middle = ctrl0.lerp(ctrl1, 0.5).to_point();
+            let weight = f32::midpoint(1.0, weight).sqrt();
+
+            self.add_conic(from, ctrl0.to_point(), middle, weight, depth + 1);
+            self.add_conic(middle, ctrl1.to_point(), to, weight, depth + 1);
+
+            return;
+        }
+
+        let k = 4.0 * weight / (3.0 * (1.0 + weight));
+
+        self.cubic_to(from.lerp(ctrl, k), to.lerp(ctrl, k), to);
+    }
+
+    fn finish_contour(&mut self) {
+        if !self.current.is_empty() {
+            self.contours.push(core::mem::take(&mut self.current));
+        }
+    }
+}
+
+impl PathBuilder for CubicsBuilder {
+    type Path = Vec<Vec<Cubic>>;
+
+    fn move_to(&mut self, point: Point) {
+        self.finish_contour();
+
+        self.start = point;
+        self.last = point;
+    }
+
+    fn line_to(&mut self, point: Point) {
+        self.current.push(Cubic::straight_line(self.last, point));
+
+        self.last = point;
+    }
+
+    fn cubic_to(&mut self, ctrl1: Point, ctrl2: Point, to: Point) {
+        self.current.push(Cubic::new(self.last, ctrl1, ctrl2, to));
+
+        self.last = to;
+    }
+
+    fn close(&mut self) {
+        if (self.last - self.start).length() > DISTANCE_EPSILON {
+            self.line_to(self.start);
+        }
+
+        self.finish_contour();
+
+        self.last = self.start;
+    }
+
+    fn build(mut self) -> Self::Path {
+        self.finish_contour();
+
+        self.contours
+    }
+}
+
+#[cfg(feature = "ttf-parser")]
+impl ttf_parser::OutlineBuilder for CubicsBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        PathBuilder::move_to(self, Point::new(x, y));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        PathBuilder::line_to(self, Point::new(x, y));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        Self::quad_to(self, Point::new(x1, y1), Point::new(x, y));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.cubic_to(Point::new(x1, y1), Point::new(x2, y2), Point::new(x, y));
+    }
+
+    fn close(&mut self) {
+        PathBuilder::close(self);
+    }
+}
+
+/// Returns the contours of the outline of the glyph with `glyph_id` in `face`
+/// as cubics, or [`None`] if the glyph has no outline.
+///
+/// Coordinates are in font units, with the y axis flipped so that it points
+/// down and the baseline at zero.
+#[cfg(feature = "ttf-parser")]
+pub fn glyph_cubics(face: &ttf_parser::Face<'_>, glyph_id: ttf_parser::GlyphId) -> Option<Vec<Vec<Cubic>>> {
+    let mut builder = CubicsBuilder::new();
+
+    face.outline_glyph(glyph_id, &mut builder)?;
+
+    let flip = |point: Point| Point::new(point.x, -point.y);
+
+    Some(
+        builder
+            .build()
+            .into_iter()
+            .map(|contour| contour.into_iter().map(|cubic| cubic.transformed(&flip)).collect())
+            .collect(),
+    )
+}
+
 #[cfg(feature = "kurbo")]
 impl PathBuilder for kurbo::BezPath {
     type Path = Self;
@@ -70,6 +506,162 @@ impl PathBuilder for kurbo::BezPath {
     }
 }
 
+#[cfg(feature = "kurbo")]
+impl ToCubics for kurbo::BezPath {
+    #[allow(clippy::cast_possible_truncation)]
+    fn to_cubics(&self) -> Vec<Vec<Cubic>> {
+        let point = |point: kurbo::Point| Point::new(point.x as f32, point.y as f32);
+        let mut builder = CubicsBuilder::new();
+
+        for element in self.elements() {
+            match *element {
+                kurbo::PathEl::MoveTo(to) => builder.move_to(point(to)),
+                kurbo::PathEl::LineTo(to) => builder.line_to(point(to)),
+                kurbo::PathEl::QuadTo(ctrl, to) => builder.quad_to(point(ctrl), point(to)),
+                kurbo::PathEl::CurveTo(ctrl1, ctrl2, to) => builder.cubic_to(point(ctrl1), point(ctrl2), point(to)),
+                kurbo::PathEl::ClosePath => builder.close(),
+            }
+        }
+
+        builder.build()
+    }
+}
+
+#[cfg(feature = "kurbo")]
+impl From<PathEvent> for kurbo::PathEl {
+    fn from(event: PathEvent) -> Self {
+        let point = |point: Point| kurbo::Point::new(point.x.into(), point.y.into());
+
+        match event {
+            PathEvent::MoveTo(to) => Self::MoveTo(point(to)),
+            PathEvent::LineTo(to) => Self::LineTo(point(to)),
+            PathEvent::CubicTo { ctrl1, ctrl2, to } => Self::CurveTo(point(ctrl1), point(ctrl2), point(to)),
+            PathEvent::Close => Self::ClosePath,
+        }
+    }
+}
+
+#[cfg(feature = "kurbo")]
+fn kurbo_cubic(cubic: Cubic) -> kurbo::CubicBez {
+    let [p0, p1, p2, p3] = cubic.points.map(|point| kurbo::Point::new(point.x.into(), point.y.into()));
+
+    kurbo::CubicBez::new(p0, p1, p2, p3)
+}
+
+/// Returns the winding number of the closed outline made of `cubics` around
+/// `point`, counting crossings of a ray going from it along the X axis.
+///
+/// Winding numbers of single segments aren't exposed by kurbo, so cubics are
+/// split into parts monotonic in Y, whose single crossing is found by
+/// bisection.
+#[cfg(feature = "kurbo")]
+fn kurbo_winding<I: IntoIterator<Item = Cubic>>(cubics: I, point: kurbo::Point) -> i32 {
+    use kurbo::{ParamCurve, ParamCurveExtrema};
+
+    const BISECTIONS: usize = 32;
+
+    let mut winding = 0;
+
+    for cubic in cubics.into_iter().map(kurbo_cubic) {
+        for range in cubic.extrema_ranges() {
+            let part = cubic.subsegment(range);
+            let upwards = part.p0.y < part.p3.y;
+            let (low, high) = if upwards { (part.p0.y, part.p3.y) } else { (part.p3.y, part.p0.y) };
+
+            if point.y < low || point.y >= high {
+                continue;
+            }
+
+            let (mut start, mut end) = (0.0, 1.0);
+
+            for _ in 0..BISECTIONS {
+                let middle = f64::midpoint(start, end);
+
+                if (part.eval(middle).y < point.y) == upwards {
+                    start = middle;
+                } else {
+                    end = middle;
+                }
+            }
+
+            if part.eval(f64::midpoint(start, end)).x > point.x {
+                winding += if upwards { 1 } else { -1 };
+            }
+        }
+    }
+
+    winding
+}
+
+#[cfg(feature = "kurbo")]
+impl kurbo::Shape for crate::RoundedPolygon {
+    type PathElementsIter<'iter> = core::iter::Map<PathEvents<core::iter::Copied<core::slice::Iter<'iter, Cubic>>>, fn(PathEvent) -> kurbo::PathEl>;
+
+    fn path_elements(&self, _tolerance: f64) -> Self::PathElementsIter<'_> {
+        self.path_events().map(kurbo::PathEl::from)
+    }
+
+    fn area(&self) -> f64 {
+        self.cubics
+            .iter()
+            .copied()
+            .map(|cubic| kurbo::ParamCurveArea::signed_area(&kurbo_cubic(cubic)))
+            .sum()
+    }
+
+    fn perimeter(&self, accuracy: f64) -> f64 {
+        self.cubics
+            .iter()
+            .copied()
+            .map(|cubic| kurbo::ParamCurveArclen::arclen(&kurbo_cubic(cubic), accuracy))
+            .sum()
+    }
+
+    fn winding(&self, pt: kurbo::Point) -> i32 {
+        kurbo_winding(self.cubics.iter().copied(), pt)
+    }
+
+    fn bounding_box(&self) -> kurbo::Rect {
+        self.cubics
+            .iter()
+            .copied()
+            .map(|cubic| kurbo::ParamCurveExtrema::bounding_box(&kurbo_cubic(cubic)))
+            .reduce(|aabb, cubic_aabb| aabb.union(cubic_aabb))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "kurbo")]
+impl<'a> kurbo::Shape for crate::MorphFrame<'a> {
+    type PathElementsIter<'iter>
+        = core::iter::Map<PathEvents<crate::MorphCubics<'a>>, fn(PathEvent) -> kurbo::PathEl>
+    where
+        'a: 'iter;
+
+    fn path_elements(&self, _tolerance: f64) -> Self::PathElementsIter<'_> {
+        self.path_events().map(kurbo::PathEl::from)
+    }
+
+    fn area(&self) -> f64 {
+        self.cubics().map(|cubic| kurbo::ParamCurveArea::signed_area(&kurbo_cubic(cubic))).sum()
+    }
+
+    fn perimeter(&self, accuracy: f64) -> f64 {
+        self.cubics().map(|cubic| kurbo::ParamCurveArclen::arclen(&kurbo_cubic(cubic), accuracy)).sum()
+    }
+
+    fn winding(&self, pt: kurbo::Point) -> i32 {
+        kurbo_winding(self.cubics(), pt)
+    }
+
+    fn bounding_box(&self) -> kurbo::Rect {
+        self.cubics()
+            .map(|cubic| kurbo::ParamCurveExtrema::bounding_box(&kurbo_cubic(cubic)))
+            .reduce(|aabb, cubic_aabb| aabb.union(cubic_aabb))
+            .unwrap_or_default()
+    }
+}
+
 #[cfg(feature = "lyon")]
 impl<T: lyon_tessellation::path::traits::PathBuilder + lyon_tessellation::path::traits::Build> PathBuilder
     for lyon_tessellation::path::builder::NoAttributes<T>
@@ -97,6 +689,28 @@ impl<T: lyon_tessellation::path::traits::PathBuilder + lyon_tessellation::path::
     }
 }
 
+#[cfg(feature = "lyon")]
+impl ToCubics for lyon_tessellation::path::Path {
+    fn to_cubics(&self) -> Vec<Vec<Cubic>> {
+        use lyon_tessellation::path::Event;
+
+        let mut builder = CubicsBuilder::new();
+
+        for event in self {
+            match event {
+                Event::Begin { at } => builder.move_to(at),
+                Event::Line { to, .. } => builder.line_to(to),
+                Event::Quadratic { ctrl, to, .. } => builder.quad_to(ctrl, to),
+                Event::Cubic { ctrl1, ctrl2, to, .. } => builder.cubic_to(ctrl1, ctrl2, to),
+                Event::End { close: true, .. } => builder.close(),
+                Event::End { close: false, .. } => {}
+            }
+        }
+
+        builder.build()
+    }
+}
+
 #[cfg(feature = "tiny-skia")]
 impl PathBuilder for tiny_skia_path::PathBuilder {
     type Path = Option<tiny_skia_path::Path>;
@@ -122,6 +736,28 @@ impl PathBuilder for tiny_skia_path::PathBuilder {
     }
 }
 
+#[cfg(feature = "tiny-skia")]
+impl ToCubics for tiny_skia_path::Path {
+    fn to_cubics(&self) -> Vec<Vec<Cubic>> {
+        use tiny_skia_path::PathSegment;
+
+        let point = |point: tiny_skia_path::Point| Point::new(point.x, point.y);
+        let mut builder = CubicsBuilder::new();
+
+        for segment in self.segments() {
+            match segment {
+                PathSegment::MoveTo(to) => builder.move_to(point(to)),
+                PathSegment::LineTo(to) => builder.line_to(point(to)),
+                PathSegment::QuadTo(ctrl, to) => builder.quad_to(point(ctrl), point(to)),
+                PathSegment::CubicTo(ctrl1, ctrl2, to) => builder.cubic_to(point(ctrl1), point(ctrl2), point(to)),
+                PathSegment::Close => builder.close(),
+            }
+        }
+
+        builder.build()
+    }
+}
+
 #[cfg(feature = "skia")]
 impl PathBuilder for skia_safe::PathBuilder {
     type Path = skia_safe::Path;
@@ -171,3 +807,28 @@ impl PathBuilder for skia_safe::Path {
         self
     }
 }
+
+#[cfg(feature = "skia")]
+impl ToCubics for skia_safe::Path {
+    fn to_cubics(&self) -> Vec<Vec<Cubic>> {
+        use skia_safe::path::{Iter, Verb};
+
+        let point = |point: skia_safe::Point| Point::new(point.x, point.y);
+        let mut builder = CubicsBuilder::new();
+        let mut iter = Iter::new(self, false);
+
+        while let Some((verb, points)) = iter.next() {
+            match (verb, points.as_slice()) {
+                (Verb::Move, &[to]) => builder.move_to(point(to)),
+                (Verb::Line, &[_, to]) => builder.line_to(point(to)),
+                (Verb::Quad, &[_, ctrl, to]) => builder.quad_to(point(ctrl), point(to)),
+                (Verb::Conic, &[_, ctrl, to]) => builder.conic_to(point(ctrl), point(to), iter.conic_weight().unwrap_or(1.0)),
+                (Verb::Cubic, &[_, ctrl1, ctrl2, to]) => builder.cubic_to(point(ctrl1), point(ctrl2), point(to)),
+                (Verb::Close, _) => builder.close(),
+                _ => {}
+            }
+        }
+
+        builder.build()
+    }
+}
@@ -0,0 +1,86 @@
+//! Contains helpers for rendering polygons and morphs on the CPU with
+//! `tiny-skia`, e.g. for previews and tests.
+
+use std::{io, path::Path};
+
+use tiny_skia::{Color, FillRule, Paint, Pixmap, PixmapMut, Stroke, Transform};
+
+use crate::{Morph, RoundedPolygon, shapes};
+
+/// Fills the `polygon` on the `pixmap`.
+pub fn fill_polygon(pixmap: &mut PixmapMut<'_>, polygon: &RoundedPolygon, paint: &Paint<'_>, transform: Transform) {
+    if let Some(path) = polygon.as_path::<tiny_skia::PathBuilder>(false, true) {
+        pixmap.fill_path(&path, paint, FillRule::Winding, transform, None);
+    }
+}
+
+/// Strokes the outline of the `polygon` on the `pixmap`.
+pub fn stroke_polygon(pixmap: &mut PixmapMut<'_>, polygon: &RoundedPolygon, paint: &Paint<'_>, stroke: &Stroke, transform: Transform) {
+    if let Some(path) = polygon.as_path::<tiny_skia::PathBuilder>(false, true) {
+        pixmap.stroke_path(&path, paint, stroke, transform, None);
+    }
+}
+
+/// Fills the transition state of the `morph` at the given `progress` on the
+/// `pixmap`.
+pub fn fill_morph(pixmap: &mut PixmapMut<'_>, morph: &Morph, progress: f32, paint: &Paint<'_>, transform: Transform) {
+    if let Some(path) = morph.as_path::<tiny_skia::PathBuilder>(progress, false, true) {
+        pixmap.fill_path(&path, paint, FillRule::Winding, transform, None);
+    }
+}
+
+/// Strokes the outline of the transition state of the `morph` at the given
+/// `progress` on the `pixmap`.
+pub fn stroke_morph(pixmap: &mut PixmapMut<'_>, morph: &Morph, progress: f32, paint: &Paint<'_>, stroke: &Stroke, transform: Transform) {
+    if let Some(path) = morph.as_path::<tiny_skia::PathBuilder>(progress, false, true) {
+        pixmap.stroke_path(&path, paint, stroke, transform, None);
+    }
+}
+
+/// Renders all shapes from [`shapes::all`] into a grid with the given number
+/// of `columns`, each one in a square cell of `cell_size` pixels, filled with
+/// `paint` on the `background` color.
+///
+/// Returns `None` if the size of the image is zero or too big.
+pub fn render_catalog(cell_size: u32, columns: u32, paint: &Paint<'_>, background: Color) -> Option<Pixmap> {
+    let shapes = shapes::all();
+    let count = u32::try_from(shapes.len()).ok()?;
+    let columns = columns.clamp(1, count);
+    let mut pixmap = Pixmap::new(cell_size.checked_mul(columns)?, cell_size.checked_mul(count.div_ceil(columns))?)?;
+
+    pixmap.fill(background);
+
+    let cell = cell_size as f32;
+    let padding = cell / 10.0;
+
+    for (index, (_, shape)) in (0..count).zip(shapes) {
+        // Shapes are normalized, so they fit into a unit square
+        let transform = Transform::from_scale(padding.mul_add(-2.0, cell), padding.mul_add(-2.0, cell)).post_translate(
+            ((index % columns) as f32).mul_add(cell, padding),
+            ((index / columns) as f32).mul_add(cell, padding),
+        );
+
+        fill_polygon(&mut pixmap.as_mut(), &shape, paint, transform);
+    }
+
+    Some(pixmap)
+}
+
+/// Renders the shapes catalog as in [`render_catalog`], in a dark color on a
+/// white background, and saves it as a PNG image at `path`.
+///
+/// # Errors
+///
+/// Returns an error if the size of the image is invalid or the image couldn't
+/// be encoded or written.
+pub fn save_catalog_png<P: AsRef<Path>>(path: P, cell_size: u32, columns: u32) -> io::Result<()> {
+    let mut paint = Paint::default();
+
+    paint.set_color_rgba8(0x1D, 0x1B, 0x20, 0xFF);
+    paint.anti_alias = true;
+
+    render_catalog(cell_size, columns, &paint, Color::WHITE)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Invalid catalog size"))?
+        .save_png(path)
+        .map_err(io::Error::other)
+}
@@ -1,11 +1,12 @@
 use core::f32;
+use std::vec;
 
 use crate::{
-    Cubic, Feature, RoundedPolygonBuilder,
-    geometry::{Aabb, GeometryExt, Point, PointTransformer, Size, Vector},
-    path::{PathBuilder, add_cubics},
+    ArcLengthMeasurer, Cubic, Feature, Fit, LengthMeasurer, MeasuredPolygon, OutlineSample, RoundedPolygonBuilder,
+    geometry::{Aabb, Angle, GeometryExt, Matrix2, Point, PointTransformer, Size, TransformExt, Vector},
+    path::{OptionCubics, PathBuilder, PathEvents, PathOptions, ToCubics, add_cubics, add_events},
     polygon_builder::{Circle, Pill, PillStar, Rectangle, Star},
-    util::radial_to_cartesian,
+    util::{centroid, flatten, radial_to_cartesian},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
@@ -242,17 +243,81 @@ impl RoundedCorner {
     }
 }
 
+/// The vertices and roundings a [`RoundedPolygon`] was constructed from with
+/// [`RoundedPolygon::from_vertices`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonSource {
+    pub vertices: Vec<Point>,
+    /// Rounding of each vertex, with the same length as `vertices`.
+    pub roundings: Vec<CornerRounding>,
+    /// The center given to [`RoundedPolygon::from_vertices`], or [`None`] if
+    /// it was computed from the vertices, in which case it's computed again
+    /// whenever the polygon is rebuilt.
+    pub center: Option<Point>,
+}
+
+impl PolygonSource {
+    /// Rebuilds the polygon described by this source.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the source contains fewer than 3 vertices, or if the number
+    /// of roundings does not correspond to the number of vertices.
+    pub fn build(&self) -> RoundedPolygon {
+        RoundedPolygon::from_vertices(
+            &self.vertices,
+            CornerRounding::UNROUNDED,
+            &self.roundings,
+            self.center.unwrap_or_else(|| Point::splat(f32::MIN)),
+        )
+    }
+
+    /// Returns the center of the polygon described by this source.
+    pub fn center(&self) -> Point {
+        self.center.unwrap_or_else(|| center_from_vertices(&self.vertices))
+    }
+
+    /// Returns the source transformed with a similarity `matrix`, which scales
+    /// rounding radii by its scale factor.
+    fn transformed(self, matrix: &Matrix2) -> Self {
+        let scale = matrix.determinant().abs().sqrt();
+
+        Self {
+            vertices: self.vertices.iter().map(|&vertex| matrix.transform(vertex)).collect(),
+            roundings: self
+                .roundings
+                .into_iter()
+                .map(|rounding| CornerRounding::smoothed(rounding.radius * scale, rounding.smoothing))
+                .collect(),
+            center: self.center.map(|center| matrix.transform(center)),
+        }
+    }
+}
+
 /// [`RoundedPolygon`] allows simple construction of polygonal shapes with
 /// optional rounding at the vertices.
 ///
 /// Polygons can be constructed with either the number of vertices desired or an
 /// ordered list of vertices.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Polygons also keep their construction source (see
+/// [`RoundedPolygon::source`]) in a private field, so they can't be created
+/// with a struct literal; use [`RoundedPolygon::new`] or
+/// [`RoundedPolygon::from_features`] for features built by hand instead. The
+/// source is ignored when comparing polygons.
+#[derive(Debug, Clone)]
 pub struct RoundedPolygon {
     pub features: Vec<Feature>,
     pub center: Point,
     /// A flattened version of the [`Feature`]s.
     pub cubics: Vec<Cubic>,
+    pub(crate) source: Option<PolygonSource>,
+}
+
+impl PartialEq for RoundedPolygon {
+    fn eq(&self, other: &Self) -> bool {
+        self.features == other.features && self.center == other.center && self.cubics == other.cubics
+    }
 }
 
 impl RoundedPolygon {
@@ -327,7 +392,12 @@ impl RoundedPolygon {
             cubics.push(Cubic::new(center, center, center, center));
         }
 
-        Self { features, center, cubics }
+        Self {
+            features,
+            center,
+            cubics,
+            source: None,
+        }
     }
 
     pub fn from_features(features: Vec<Feature>, center: Option<Point>) -> Self {
@@ -351,6 +421,110 @@ impl RoundedPolygon {
         Self::new(features, center)
     }
 
+    /// Creates a rounded polygon from the cubics of a closed outline, e.g. a
+    /// contour returned by [`ToCubics::to_cubics`].
+    ///
+    /// Straight cubics become edges and runs of smoothly joined curved cubics
+    /// become corners, while sharp joints become unrounded corners, so the
+    /// result morphs like polygons created from vertices. Runs are split where
+    /// they change between convex and concave, and where they turn by more
+    /// than a quarter turn. The outline is closed with a line if needed, and
+    /// reversed if it winds in the opposite direction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is empty.
+    pub fn from_cubics(input: &[Cubic]) -> Self {
+        assert!(!input.is_empty(), "Outlines need at least one cubic");
+
+        let mut cubics = input.iter().copied().filter(|cubic| !cubic.zero_length()).collect::<Vec<_>>();
+
+        // A degenerate outline, e.g. of a `M x,y Z` contour, is an empty polygon
+        let (Some(first), Some(last)) = (cubics.first(), cubics.last()) else {
+            return Self::from_features(Vec::new(), Some(input[0].anchor0()));
+        };
+        let (start, end) = (first.anchor0(), last.anchor1());
+
+        if (end - start).length() > DISTANCE_EPSILON {
+            cubics.push(Cubic::straight_line(end, start));
+        }
+
+        let outline = cubics.iter().flat_map(|cubic| [cubic.anchor0(), cubic.point_on_curve(0.5)]).collect::<Vec<_>>();
+
+        if signed_area(&outline) < 0.0 {
+            cubics = cubics.into_iter().rev().map(Cubic::reversed).collect();
+        }
+
+        let n = cubics.len();
+        let straight = cubics.iter().map(Cubic::is_straight).collect::<Vec<_>>();
+        // The joint `i` is where the cubic `i` starts
+        let joints = (0..n)
+            .map(|i| (end_direction(&cubics[(i + n - 1) % n]), start_direction(&cubics[i])))
+            .collect::<Vec<_>>();
+        let sharp = joints
+            .iter()
+            .map(|(incoming, outgoing)| incoming.dot(*outgoing) < SMOOTH_JOINT_COS)
+            .collect::<Vec<_>>();
+        let starts_feature = |i: usize| sharp[i] || straight[i] || straight[(i + n - 1) % n];
+
+        // Start at a feature boundary, so that no feature wraps around
+        let offset = (0..n).find(|&i| starts_feature(i)).unwrap_or(0);
+        let mut features = Vec::new();
+        let mut group = Vec::<Cubic>::new();
+        let mut turned = 0.0f32;
+
+        for i in (0..n).map(|k| (k + offset) % n) {
+            let (outgoing, incoming) = (start_direction(&cubics[i]), end_direction(&cubics[i]));
+            let turn = outgoing.cross(incoming).atan2(outgoing.dot(incoming));
+            // Smooth runs are split where they change direction or turn too far
+            let splits_run = (turn.abs() > TURN_EPSILON && turn * turned < 0.0) || (turned + turn).abs() > MAX_SMOOTH_TURN;
+
+            if starts_feature(i) || splits_run || group.is_empty() {
+                features.extend(feature_of(core::mem::take(&mut group)));
+                turned = 0.0;
+
+                if sharp[i] {
+                    let (incoming, outgoing) = joints[i];
+                    let vertex = cubics[i].anchor0();
+
+                    features.push(Feature::corner(vec![Cubic::straight_line(vertex, vertex)], incoming.cross(outgoing) > 0.0));
+                }
+            }
+
+            group.push(cubics[i]);
+
+            if turn.abs() > TURN_EPSILON {
+                turned += turn;
+            }
+        }
+
+        features.extend(feature_of(group));
+
+        Self::from_features(features, None)
+    }
+
+    /// Creates rounded polygons from each contour of the `path`, see
+    /// [`from_cubics`](Self::from_cubics).
+    pub fn from_path<P: ToCubics + ?Sized>(path: &P) -> Vec<Self> {
+        path.to_cubics().iter().map(|contour| Self::from_cubics(contour)).collect()
+    }
+
+    /// Creates rounded polygons from each contour of the outline of the glyph
+    /// with `glyph_id` in `face`, see
+    /// [`glyph_cubics`](crate::path::glyph_cubics)
+    /// and [`from_cubics`](Self::from_cubics).
+    ///
+    /// Polygons have no holes, so the inner contours of glyphs like `o` become
+    /// separate polygons. Glyphs without an outline give no polygons.
+    #[cfg(feature = "ttf-parser")]
+    pub fn from_glyph(face: &ttf_parser::Face<'_>, glyph_id: ttf_parser::GlyphId) -> Vec<Self> {
+        crate::path::glyph_cubics(face, glyph_id)
+            .unwrap_or_default()
+            .iter()
+            .map(|contour| Self::from_cubics(contour))
+            .collect()
+    }
+
     fn builder<D>(data: D) -> RoundedPolygonBuilder<D> {
         RoundedPolygonBuilder {
             data,
@@ -521,17 +695,123 @@ impl RoundedPolygon {
             )]));
         }
 
-        let center = if center.x == f32::MIN || center.y == f32::MIN {
-            center_from_vertices(vertices)
-        } else {
-            center
-        };
+        #[allow(clippy::float_cmp)] // `f32::MIN` is an exact sentinel value
+        let explicit_center = (center.x != f32::MIN && center.y != f32::MIN).then_some(center);
+        let center = explicit_center.unwrap_or_else(|| center_from_vertices(vertices));
+
+        let roundings = (0..n).map(|i| per_vertex_rounding.get(i).copied().unwrap_or(rounding)).collect();
+
+        Self {
+            source: Some(PolygonSource {
+                vertices: vertices.to_vec(),
+                roundings,
+                center: explicit_center,
+            }),
+            ..Self::new(temp_features, center)
+        }
+    }
+
+    /// Returns a [`RoundedPolygon`] with features transformed using the
+    /// provided `f`, which implements [`PointTransformer`] trait, subdivided as
+    /// needed to stay within `tolerance` of the exact result.
+    ///
+    /// This is useful for non-linear transformations like the ones in
+    /// [`warp`](crate::warp), where transforming only the points of long
+    /// cubics is inaccurate.
+    ///
+    /// The construction source is dropped, see [`RoundedPolygon::transformed`].
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn transformed_adaptive<T: PointTransformer>(self, f: T, tolerance: f32) -> Self {
+        let center = f.transform(self.center);
+
+        Self::new(
+            self.features.into_iter().map(|feature| feature.transformed_adaptive(&f, tolerance)).collect(),
+            center,
+        )
+    }
+
+    /// Returns a [`RoundedPolygon`] transformed using the provided affine
+    /// `matrix`.
+    ///
+    /// If the transformation flips winding (e.g. a mirror), the outline is also
+    /// reversed, so the polygon keeps its winding direction and the `convex`
+    /// flags of its corners stay correct.
+    ///
+    /// The construction source (see [`RoundedPolygon::source`]) is kept only
+    /// if the transformation is a similarity, since other transformations
+    /// stretch rounded corners into shapes that can't be rebuilt from it.
+    #[must_use]
+    pub fn transformed_affine(mut self, matrix: &Matrix2) -> Self {
+        let source = self.source.take().filter(|_| matrix.is_similarity());
+
+        if !matrix.flips_winding() {
+            return Self {
+                source: source.map(|source| source.transformed(matrix)),
+                ..self.transformed(*matrix)
+            };
+        }
+
+        let center = matrix.transform(self.center);
+        let mut features = self.features.into_iter().map(|feature| feature.transformed_affine(matrix)).collect::<Vec<_>>();
+
+        // Keep the first corner first
+        features.reverse();
+
+        if !features.is_empty() {
+            features.rotate_right(1);
+        }
+
+        let source = source.map(|source| {
+            let mut source = source.transformed(matrix);
 
-        Self::new(temp_features, center)
+            source.vertices[1..].reverse();
+            source.roundings[1..].reverse();
+
+            source
+        });
+
+        Self {
+            source,
+            ..Self::new(features, center)
+        }
+    }
+
+    /// Returns a [`RoundedPolygon`] rotated by `angle` around `center`.
+    #[must_use]
+    pub fn rotated(self, angle: Angle, center: Point) -> Self {
+        self.transformed_affine(&Matrix2::rotation_about(angle, center))
+    }
+
+    /// Returns a [`RoundedPolygon`] scaled by `x` and `y` factors relative to
+    /// `center`.
+    #[must_use]
+    pub fn scaled(self, x: f32, y: f32, center: Point) -> Self {
+        self.transformed_affine(&Matrix2::scale_about(x, y, center))
+    }
+
+    /// Returns a [`RoundedPolygon`] reflected across the line going through
+    /// `origin` in the given `direction`.
+    #[must_use]
+    pub fn mirrored(self, origin: Point, direction: Vector) -> Self {
+        self.transformed_affine(&Matrix2::mirror(origin, direction))
+    }
+
+    /// Returns the vertices and roundings the polygon was constructed from, if
+    /// it was created with [`RoundedPolygon::from_vertices`] or one of the
+    /// builders, and only transformed with similarity transformations since.
+    pub const fn source(&self) -> Option<&PolygonSource> {
+        self.source.as_ref()
     }
 
     /// Returns a [`RoundedPolygon`] with features transformed using the
     /// provided reference to type that implements [`PointTransformer`] trait.
+    ///
+    /// The construction source (see [`RoundedPolygon::source`]) is dropped,
+    /// since an arbitrary transformation may no longer describe a polygon that
+    /// can be rebuilt from transformed vertices. Use
+    /// [`RoundedPolygon::transformed_affine`] to keep it for similarity
+    /// transformations.
     #[must_use]
     #[allow(clippy::needless_pass_by_value)]
     pub fn transformed<T: PointTransformer>(self, f: T) -> Self {
@@ -540,6 +820,95 @@ impl RoundedPolygon {
         Self::new(self.features.into_iter().map(|feature| feature.transformed(&f)).collect(), center)
     }
 
+    /// Returns a [`RoundedPolygon`] rebuilt with the vertex at `index` moved to
+    /// `position`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polygon has no construction source (see
+    /// [`RoundedPolygon::source`]) or if `index` is out of bounds.
+    #[must_use]
+    pub fn with_vertex_moved(self, index: usize, position: Point) -> Self {
+        self.edited(|source| source.vertices[index] = position)
+    }
+
+    /// Returns a [`RoundedPolygon`] rebuilt with the vertex at `index` rounded
+    /// using `rounding`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polygon has no construction source (see
+    /// [`RoundedPolygon::source`]) or if `index` is out of bounds.
+    #[must_use]
+    pub fn with_rounding_at(self, index: usize, rounding: CornerRounding) -> Self {
+        self.edited(|source| source.roundings[index] = rounding)
+    }
+
+    /// Returns a [`RoundedPolygon`] rebuilt with a new vertex inserted at
+    /// `index`, shifting all vertices after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polygon has no construction source (see
+    /// [`RoundedPolygon::source`]) or if `index` is greater than the number of
+    /// vertices.
+    #[must_use]
+    pub fn insert_vertex(self, index: usize, position: Point, rounding: CornerRounding) -> Self {
+        self.edited(|source| {
+            source.vertices.insert(index, position);
+            source.roundings.insert(index, rounding);
+        })
+    }
+
+    /// Returns a [`RoundedPolygon`] rebuilt without the vertex at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polygon has no construction source (see
+    /// [`RoundedPolygon::source`]), if `index` is out of bounds or if the
+    /// polygon would be left with fewer than 3 vertices.
+    #[must_use]
+    pub fn remove_vertex(self, index: usize) -> Self {
+        self.edited(|source| {
+            source.vertices.remove(index);
+            source.roundings.remove(index);
+        })
+    }
+
+    /// Returns a [`RoundedPolygon`] with vertices scaled around the center so
+    /// that its bounds have the given `size`, then rebuilt with the original
+    /// rounding radii.
+    ///
+    /// Unlike a non-uniform scale applied with [`RoundedPolygon::transformed`],
+    /// corners stay circular. Since they are not stretched, the resulting
+    /// bounds may slightly differ from `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polygon has no construction source (see
+    /// [`RoundedPolygon::source`]).
+    #[must_use]
+    pub fn resized(self, size: Size) -> Self {
+        let current = self.aabb(false).size();
+        let scale = Vector::new(size.width / current.width, size.height / current.height);
+
+        self.edited(|source| {
+            let center = source.center();
+
+            for vertex in &mut source.vertices {
+                *vertex = center + (*vertex - center).component_mul(scale);
+            }
+        })
+    }
+
+    fn edited<F: FnOnce(&mut PolygonSource)>(self, edit: F) -> Self {
+        let mut source = self.source.expect("Polygon has no construction source to edit");
+
+        edit(&mut source);
+
+        source.build()
+    }
+
     /// Returns an axis-aligned bounding box describing bounds of the polygon.
     ///
     /// If `approximate` is `true`, a fast but sometimes inaccurate algorithm is
@@ -561,6 +930,21 @@ impl RoundedPolygon {
         aabb
     }
 
+    /// Returns the centroid of the area enclosed by the polygon, or `None` if
+    /// the area is (close to) zero.
+    pub fn centroid(&self) -> Option<Point> {
+        centroid(self.cubics.iter().copied())
+    }
+
+    /// Moves and resizes [`RoundedPolygon`] to fit into `rect` as described by
+    /// `fit`.
+    #[must_use]
+    pub fn fit_into(self, rect: Aabb, fit: Fit) -> Self {
+        let matrix = fit.matrix(self.aabb(!fit.exact_bounds), self.centroid(), rect);
+
+        self.transformed_affine(&matrix)
+    }
+
     /// Moves and resizes [`RoundedPolygon`], so it's completely inside the 0x0
     /// -> 1x1 square, centered if there extra space in one direction.
     #[must_use]
@@ -572,7 +956,29 @@ impl RoundedPolygon {
 
         let offset = ((Point::splat(max_side) - size) / 2.0 - bounds.min).to_point();
 
-        self.transformed(|point| (point + offset.to_vector()) / max_side)
+        self.transformed_affine(&Matrix2::translation(offset.x, offset.y).then_scale(max_side.recip(), max_side.recip()))
+    }
+
+    /// Returns the outline of the polygon measured with [`LengthMeasurer`],
+    /// which approximates arc lengths with chords, and allows sampling it at
+    /// any outline progress.
+    pub fn measured(&self) -> MeasuredPolygon<LengthMeasurer> {
+        MeasuredPolygon::measure_polygon(LengthMeasurer, self)
+    }
+
+    /// Returns `n` samples of the outline equally spaced by arc length,
+    /// optionally snapping samples to the middles of corners, see
+    /// [`MeasuredPolygon::resample`]. The outline is measured with
+    /// [`ArcLengthMeasurer`].
+    pub fn resample(&self, n: usize, snap_to_corners: bool) -> Vec<OutlineSample> {
+        MeasuredPolygon::measure_polygon(ArcLengthMeasurer::default(), self).resample(n, snap_to_corners)
+    }
+
+    /// Returns the open part of the outline between the `start` and `end`
+    /// outline progress values, both shifted by `offset`, see
+    /// [`MeasuredPolygon::trimmed`].
+    pub fn trimmed(&self, start: f32, end: f32, offset: f32) -> Vec<Cubic> {
+        self.measured().trimmed(start, end, offset)
     }
 
     /// Returns a path with a drawn rounded polygon. Path is created using the
@@ -585,6 +991,36 @@ impl RoundedPolygon {
         path.build()
     }
 
+    /// Returns the outline of this polygon approximated with line segments
+    /// that stay within `tolerance` of it, as a closed ring of points without
+    /// the first point repeated at the end.
+    pub fn flattened(&self, tolerance: f32) -> Vec<Point> {
+        flatten(self.cubics.iter().copied(), tolerance)
+    }
+
+    /// Returns an iterator over the [`PathEvent`](crate::path::PathEvent)s
+    /// drawing this polygon.
+    pub fn path_events(&self) -> PathEvents<core::iter::Copied<core::slice::Iter<'_, Cubic>>> {
+        PathEvents::new(self.cubics.iter().copied())
+    }
+
+    /// Returns an iterator over the [`PathEvent`](crate::path::PathEvent)s
+    /// drawing this polygon as described by the `options`.
+    pub fn path_events_with(&self, options: PathOptions) -> PathEvents<OptionCubics<core::iter::Copied<core::slice::Iter<'_, Cubic>>>> {
+        PathEvents::with_options(self.cubics.iter().copied(), options)
+    }
+
+    /// Returns a path of this polygon drawn as described by the `options`.
+    /// Path is created using the provided `T`, which should implement
+    /// `PathBuilder` and `Default` traits.
+    pub fn as_path_with<T: PathBuilder + Default>(&self, options: PathOptions) -> T::Path {
+        let mut path = T::default();
+
+        add_events(&mut path, self.path_events_with(options));
+
+        path.build()
+    }
+
     /// Adds a rounded polygon to the `builder`.
     pub fn add_to<T: PathBuilder>(&self, builder: &mut T, repeat_path: bool, close_path: bool) {
         add_cubics(builder, repeat_path, close_path, &self.cubics);
@@ -601,6 +1037,58 @@ fn center_from_vertices(vertices: &[Point]) -> Point {
     cumulative / vertices.len() as f32
 }
 
+/// The cosine of the largest angle between tangents at which two cubics are
+/// still considered to be joined smoothly (about 5 degrees).
+const SMOOTH_JOINT_COS: f32 = 0.996;
+
+/// The largest angle in radians a corner made of smoothly joined cubics turns
+/// by, slightly more than a quarter turn to allow for rounding errors.
+const MAX_SMOOTH_TURN: f32 = f32::consts::FRAC_PI_2 + 1e-3;
+
+/// Cubics turning by less than this angle in radians don't change the
+/// direction in which a run of cubics turns.
+const TURN_EPSILON: f32 = 1e-3;
+
+/// Returns the direction in which the cubic starts, skipping control points
+/// coinciding with the anchor.
+fn start_direction(cubic: &Cubic) -> Vector {
+    [cubic.control0(), cubic.control1(), cubic.anchor1()]
+        .into_iter()
+        .map(|point| point - cubic.anchor0())
+        .find(|offset| offset.length() > DISTANCE_EPSILON)
+        .map_or_else(Vector::zero, Vector::normalize)
+}
+
+/// Returns the direction in which the cubic ends, skipping control points
+/// coinciding with the anchor.
+fn end_direction(cubic: &Cubic) -> Vector {
+    [cubic.control1(), cubic.control0(), cubic.anchor0()]
+        .into_iter()
+        .map(|point| cubic.anchor1() - point)
+        .find(|offset| offset.length() > DISTANCE_EPSILON)
+        .map_or_else(Vector::zero, Vector::normalize)
+}
+
+/// Returns the feature made of the given run of cubics, which is either a
+/// single straight cubic or smoothly joined curved ones.
+fn feature_of(cubics: Vec<Cubic>) -> Option<Feature> {
+    let middle = cubics.get(cubics.len() / 2)?;
+
+    Some(if cubics.len() == 1 && middle.is_straight() {
+        Feature::edge(cubics)
+    } else {
+        let convex = middle.derivative(0.5).cross(middle.second_derivative(0.5)) >= 0.0;
+
+        Feature::corner(cubics, convex)
+    })
+}
+
+fn signed_area(vertices: &[Point]) -> f32 {
+    let n = vertices.len();
+
+    (0..n).map(|i| vertices[i].to_vector().cross(vertices[(i + 1) % n].to_vector())).sum::<f32>() / 2.0
+}
+
 #[allow(clippy::manual_is_multiple_of)] // For MSRV compability
 fn custom_polygon(points: &[RoundedPoint], repeats: usize, center: Option<Point>, mirroring: bool) -> RoundedPolygon {
     let center = center.unwrap_or(Point::new(0.5, 0.5));
@@ -2,7 +2,7 @@
 
 use crate::{
     CornerRounding, RoundedPoint, RoundedPolygon,
-    geometry::{Angle, Matrix3, Point},
+    geometry::{Angle, Matrix2, Point},
 };
 
 const CORNER_ROUND15: CornerRounding = CornerRounding::new(0.15);
@@ -11,16 +11,16 @@ const CORNER_ROUND30: CornerRounding = CornerRounding::new(0.3);
 const CORNER_ROUND50: CornerRounding = CornerRounding::new(0.5);
 const CORNER_ROUND100: CornerRounding = CornerRounding::new(1.0);
 
-fn rotate_neg45() -> Matrix3 {
-    Matrix3::rotation(0.0, 0.0, 1.0, -Angle::degrees(45.0))
+fn rotate_neg45() -> Matrix2 {
+    Matrix2::rotation(-Angle::degrees(45.0))
 }
 
-fn rotate_neg90() -> Matrix3 {
-    Matrix3::rotation(0.0, 0.0, 1.0, -Angle::degrees(90.0))
+fn rotate_neg90() -> Matrix2 {
+    Matrix2::rotation(-Angle::degrees(90.0))
 }
 
-fn rotate_neg135() -> Matrix3 {
-    Matrix3::rotation(0.0, 0.0, 1.0, -Angle::degrees(135.0))
+fn rotate_neg135() -> Matrix2 {
+    Matrix2::rotation(-Angle::degrees(135.0))
 }
 
 /// A circle shape.
@@ -96,7 +96,7 @@ pub fn semi_circle() -> RoundedPolygon {
 pub fn oval() -> RoundedPolygon {
     RoundedPolygon::circle()
         .build()
-        .transformed(Matrix3::scale(1.0, 0.64, 1.0))
+        .transformed(Matrix2::scale(1.0, 0.64))
         .transformed(rotate_neg45())
         .normalized()
 }
@@ -369,8 +369,6 @@ pub fn flower() -> RoundedPolygon {
 
 /// A puffy shape.
 pub fn puffy() -> RoundedPolygon {
-    let matrix = Matrix3::scale(1.0, 0.742, 1.0);
-
     RoundedPolygon::from_points(
         &[
             RoundedPoint::unrounded(Point::new(0.500, 0.053)),
@@ -388,7 +386,7 @@ pub fn puffy() -> RoundedPolygon {
         2,
         true,
     )
-    .transformed(|point| matrix.transform_point2d(point).unwrap_or(point))
+    .transformed(Matrix2::scale(1.0, 0.742))
     .normalized()
 }
 
@@ -478,3 +476,45 @@ pub fn heart() -> RoundedPolygon {
     )
     .normalized()
 }
+
+/// Returns all shapes of this module with their names, in the order of the
+/// M3 shape library.
+pub fn all() -> Vec<(&'static str, RoundedPolygon)> {
+    vec![
+        ("circle", circle(None)),
+        ("square", square()),
+        ("slanted", slanted()),
+        ("arch", arch()),
+        ("fan", fan()),
+        ("arrow", arrow()),
+        ("semi_circle", semi_circle()),
+        ("oval", oval()),
+        ("pill", pill()),
+        ("triangle", triangle()),
+        ("diamond", diamond()),
+        ("clam_shell", clam_shell()),
+        ("pentagon", pentagon()),
+        ("gem", gem()),
+        ("sunny", sunny()),
+        ("very_sunny", very_sunny()),
+        ("cookie4", cookie4()),
+        ("cookie6", cookie6()),
+        ("cookie7", cookie7()),
+        ("cookie9", cookie9()),
+        ("cookie12", cookie12()),
+        ("ghostish", ghostish()),
+        ("clover4", clover4()),
+        ("clover8", clover8()),
+        ("burst", burst()),
+        ("soft_burst", soft_burst()),
+        ("boom", boom()),
+        ("soft_boom", soft_boom()),
+        ("flower", flower()),
+        ("puffy", puffy()),
+        ("puffy_diamond", puffy_diamond()),
+        ("pixel_circle", pixel_circle()),
+        ("pixel_triangle", pixel_triangle()),
+        ("bun", bun()),
+        ("heart", heart()),
+    ]
+}
@@ -0,0 +1,134 @@
+//! Contains per-segment progress remapping for [`Morph`]s, allowing each
+//! matched segment to transition with its own delay (e.g. a ripple travelling
+//! around the shape).
+//!
+//! [`Morph`]: crate::Morph
+
+use core::f32;
+
+use crate::util::{positive_modulo, progress_distance};
+
+/// Describes a matched pair of cubics of a [`Morph`](crate::Morph).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MorphSegment {
+    /// Index of the pair in the morph.
+    pub index: usize,
+    /// Outline progress of the middle of the segment on the start shape, in
+    /// `0.0..1.0`.
+    pub outline_progress: f32,
+    /// Angle of the middle of the segment around the center of the start
+    /// shape, in radians in `0.0..2π`.
+    pub angle: f32,
+}
+
+/// A necessary trait for remapping the progress of individual segments of a
+/// [`Morph`](crate::Morph).
+pub trait Stagger {
+    /// Returns the progress of `segment` when the whole morph is at
+    /// `progress`.
+    fn progress(&self, progress: f32, segment: &MorphSegment) -> f32;
+}
+
+impl<F: Fn(f32, &MorphSegment) -> f32> Stagger for F {
+    fn progress(&self, progress: f32, segment: &MorphSegment) -> f32 {
+        self(progress, segment)
+    }
+}
+
+/// Delays segments based on their outline progress, so the transition travels
+/// along the outline starting from `origin`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutlineStagger {
+    /// Outline progress at which the transition starts.
+    pub origin: f32,
+    /// Portion of the whole animation by which the last segment is delayed, in
+    /// `0.0..1.0`.
+    pub spread: f32,
+    /// If `true`, the transition travels in both directions from `origin` and
+    /// meets on the opposite side of the outline.
+    pub symmetric: bool,
+}
+
+impl OutlineStagger {
+    pub const fn new(origin: f32, spread: f32) -> Self {
+        Self {
+            origin,
+            spread,
+            symmetric: false,
+        }
+    }
+
+    /// Makes the transition travel in both directions from the origin.
+    #[must_use]
+    pub const fn symmetric(mut self) -> Self {
+        self.symmetric = true;
+
+        self
+    }
+}
+
+impl Stagger for OutlineStagger {
+    fn progress(&self, progress: f32, segment: &MorphSegment) -> f32 {
+        let delay = if self.symmetric {
+            progress_distance(segment.outline_progress, self.origin) * 2.0
+        } else {
+            positive_modulo(segment.outline_progress - self.origin, 1.0)
+        };
+
+        delayed(progress, delay, self.spread)
+    }
+}
+
+/// Delays segments based on their angle around the center of the shape, so the
+/// transition sweeps around it starting from `start_angle`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AngularStagger {
+    /// Angle in radians at which the transition starts.
+    pub start_angle: f32,
+    /// Portion of the whole animation by which the last segment is delayed, in
+    /// `0.0..1.0`.
+    pub spread: f32,
+    /// If `true`, the transition sweeps in both directions from `start_angle`
+    /// and meets on the opposite side of the shape.
+    pub symmetric: bool,
+}
+
+impl AngularStagger {
+    pub const fn new(start_angle: f32, spread: f32) -> Self {
+        Self {
+            start_angle,
+            spread,
+            symmetric: false,
+        }
+    }
+
+    /// Makes the transition sweep in both directions from the start angle.
+    #[must_use]
+    pub const fn symmetric(mut self) -> Self {
+        self.symmetric = true;
+
+        self
+    }
+}
+
+impl Stagger for AngularStagger {
+    fn progress(&self, progress: f32, segment: &MorphSegment) -> f32 {
+        let turn = positive_modulo((segment.angle - self.start_angle) / f32::consts::TAU, 1.0);
+        let delay = if self.symmetric { progress_distance(turn, 0.0) * 2.0 } else { turn };
+
+        delayed(progress, delay, self.spread)
+    }
+}
+
+/// Remaps `progress` so that a segment with the given `delay` (in `0.0..=1.0`)
+/// starts after `delay * spread` and finishes `(1 - delay) * spread` before the
+/// end of the animation. Overshooting progress values are preserved.
+fn delayed(progress: f32, delay: f32, spread: f32) -> f32 {
+    if spread <= 0.0 {
+        return progress;
+    }
+
+    let spread = spread.min(1.0 - 1e-3);
+
+    (delay.mul_add(-spread, progress) / (1.0 - spread)).clamp(progress.min(0.0), progress.max(1.0))
+}
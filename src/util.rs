@@ -1,4 +1,7 @@
-use crate::geometry::Vector;
+use crate::{
+    Cubic,
+    geometry::{DISTANCE_EPSILON, Point, Vector},
+};
 
 pub fn progress_in_range(progress: f32, progress_from: f32, progress_to: f32) -> bool {
     if progress_to >= progress_from {
@@ -21,3 +24,50 @@ pub fn radial_to_cartesian(radius: f32, angle_radians: f32) -> Vector {
 pub fn positive_modulo(num: f32, modulo: f32) -> f32 {
     (num % modulo + modulo) % modulo
 }
+
+/// Nodes and weights of the 5-point Gauss-Legendre quadrature on `0.0..=1.0`,
+/// which is exact for polynomials up to degree 9.
+const GAUSS_LEGENDRE: [(f32, f32); 5] = [
+    (0.046_910_077, 0.118_463_44),
+    (0.230_765_35, 0.239_314_34),
+    (0.5, 0.284_444_45),
+    (0.769_234_65, 0.239_314_34),
+    (0.953_089_9, 0.118_463_44),
+];
+
+/// Returns the centroid of the area enclosed by a closed outline made of
+/// `cubics`, or `None` if the area is (close to) zero.
+///
+/// Integrals of Green's theorem are polynomials of degree 8 in the curve
+/// parameter, so the quadrature computes them exactly.
+pub fn centroid<I: IntoIterator<Item = Cubic>>(cubics: I) -> Option<Point> {
+    let mut area = 0.0;
+    let mut moment = Vector::zero();
+
+    for cubic in cubics {
+        for (t, weight) in GAUSS_LEGENDRE {
+            let point = cubic.point_on_curve(t).to_vector();
+            let cross = point.cross(cubic.derivative(t)) * weight;
+
+            area += cross / 2.0;
+            moment += point * cross / 3.0;
+        }
+    }
+
+    (area.abs() > DISTANCE_EPSILON * DISTANCE_EPSILON).then(|| (moment / area).to_point())
+}
+
+/// Returns the points of a closed outline made of `cubics` approximated with
+/// line segments, without repeating the first point at the end.
+pub fn flatten<I: IntoIterator<Item = Cubic>>(cubics: I, tolerance: f32) -> Vec<Point> {
+    let mut cubics = cubics.into_iter().peekable();
+    let mut points = cubics.peek().map(Cubic::anchor0).into_iter().collect::<Vec<_>>();
+
+    for cubic in cubics {
+        points.extend(cubic.flattened(tolerance));
+    }
+
+    points.pop();
+
+    points
+}
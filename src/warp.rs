@@ -0,0 +1,207 @@
+//! Contains non-linear deformations implementing [`PointTransformer`], which
+//! can be passed to [`RoundedPolygon::transformed`] or, for better accuracy,
+//! [`RoundedPolygon::transformed_adaptive`].
+//!
+//! [`RoundedPolygon::transformed`]: crate::RoundedPolygon::transformed
+//! [`RoundedPolygon::transformed_adaptive`]: crate::RoundedPolygon::transformed_adaptive
+
+use core::f32;
+
+use crate::geometry::{Aabb, Angle, DISTANCE_EPSILON, GeometryExt, Point, PointTransformer, Vector};
+
+/// Bends the plane around a circle, so that horizontal lines become arcs.
+///
+/// The horizontal line going through `origin` is bent into an arc of radius
+/// `1 / curvature`, with `origin` staying in place and lengths along the line
+/// preserved. Positive curvature bends around a center below `origin`
+/// (towards positive `y`), negative curvature around a center above it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bend {
+    pub origin: Point,
+    pub curvature: f32,
+}
+
+impl Bend {
+    pub const fn new(origin: Point, curvature: f32) -> Self {
+        Self { origin, curvature }
+    }
+}
+
+impl PointTransformer for Bend {
+    fn transform(&self, point: Point) -> Point {
+        if self.curvature.abs() < DISTANCE_EPSILON {
+            return point;
+        }
+
+        let radius = self.curvature.recip();
+        let offset = point - self.origin;
+        let (sin, cos) = (offset.x / radius).sin_cos();
+        let distance = radius - offset.y;
+
+        self.origin + Vector::new(sin * distance, cos.mul_add(-distance, radius))
+    }
+}
+
+/// Rotates points around `center` by an angle decreasing linearly from `angle`
+/// at the center to zero at `radius`, leaving points further away in place.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Twist {
+    pub center: Point,
+    pub angle: Angle,
+    pub radius: f32,
+}
+
+impl Twist {
+    pub const fn new(center: Point, angle: Angle, radius: f32) -> Self {
+        Self { center, angle, radius }
+    }
+}
+
+impl PointTransformer for Twist {
+    fn transform(&self, point: Point) -> Point {
+        let distance = (point - self.center).length();
+
+        if distance >= self.radius {
+            return point;
+        }
+
+        point.rotated(self.angle.to_degrees() * (1.0 - distance / self.radius), self.center)
+    }
+}
+
+/// Pushes points within `radius` of `center` away from it (bulge) for positive
+/// `strength`, or pulls them towards it (pinch) for negative `strength`.
+///
+/// The effect fades out smoothly towards `radius`, leaving points further away
+/// in place.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bulge {
+    pub center: Point,
+    pub radius: f32,
+    pub strength: f32,
+}
+
+impl Bulge {
+    pub const fn new(center: Point, radius: f32, strength: f32) -> Self {
+        Self { center, radius, strength }
+    }
+}
+
+impl PointTransformer for Bulge {
+    fn transform(&self, point: Point) -> Point {
+        let offset = point - self.center;
+        let distance = offset.length();
+
+        if distance >= self.radius {
+            return point;
+        }
+
+        let falloff = (distance / self.radius).mul_add(-(distance / self.radius), 1.0);
+
+        self.center + offset * (self.strength * falloff).mul_add(falloff, 1.0)
+    }
+}
+
+/// Displaces points vertically by a sine wave travelling along the `x` axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wave {
+    pub amplitude: f32,
+    pub wavelength: f32,
+    /// Phase of the wave in radians, which can be animated to make it travel.
+    pub phase: f32,
+}
+
+impl Wave {
+    pub const fn new(amplitude: f32, wavelength: f32, phase: f32) -> Self {
+        Self { amplitude, wavelength, phase }
+    }
+}
+
+impl PointTransformer for Wave {
+    fn transform(&self, point: Point) -> Point {
+        let angle = (point.x / self.wavelength).mul_add(f32::consts::TAU, self.phase);
+
+        Point::new(point.x, self.amplitude.mul_add(angle.sin(), point.y))
+    }
+}
+
+/// Maps the `source` rectangle onto an arbitrary quadrilateral, described by
+/// its corners in order: top-left, top-right, bottom-right, bottom-left.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Envelope {
+    source: Aabb,
+    corners: [Point; 4],
+    /// Coefficients of the projective mapping from the unit square, if it's a
+    /// perspective envelope.
+    homography: Option<[f32; 8]>,
+}
+
+impl Envelope {
+    /// Returns an envelope interpolating bilinearly between the corners, which
+    /// keeps evenly spaced points along the edges evenly spaced.
+    pub const fn bilinear(source: Aabb, corners: [Point; 4]) -> Self {
+        Self {
+            source,
+            corners,
+            homography: None,
+        }
+    }
+
+    /// Returns an envelope using a perspective projection, which keeps straight
+    /// lines straight.
+    pub fn perspective(source: Aabb, corners: [Point; 4]) -> Self {
+        let [p0, p1, p2, p3] = corners;
+        let d1 = p1 - p2;
+        let d2 = p3 - p2;
+        let d3 = p0 - p1 + (p2 - p3);
+        let det = d1.cross(d2);
+
+        // A parallelogram (or a degenerate quadrilateral) is an affine mapping
+        let (g, h) = if d3.square_length() < DISTANCE_EPSILON * DISTANCE_EPSILON || det.abs() < DISTANCE_EPSILON {
+            (0.0, 0.0)
+        } else {
+            (d3.cross(d2) / det, d1.cross(d3) / det)
+        };
+
+        Self {
+            source,
+            corners,
+            homography: Some([
+                g.mul_add(p1.x, p1.x - p0.x),
+                h.mul_add(p3.x, p3.x - p0.x),
+                p0.x,
+                g.mul_add(p1.y, p1.y - p0.y),
+                h.mul_add(p3.y, p3.y - p0.y),
+                p0.y,
+                g,
+                h,
+            ]),
+        }
+    }
+
+    pub const fn source(&self) -> Aabb {
+        self.source
+    }
+
+    pub const fn corners(&self) -> [Point; 4] {
+        self.corners
+    }
+}
+
+impl PointTransformer for Envelope {
+    fn transform(&self, point: Point) -> Point {
+        let size = self.source.size();
+        let u = (point.x - self.source.min.x) / size.width.max(DISTANCE_EPSILON);
+        let v = (point.y - self.source.min.y) / size.height.max(DISTANCE_EPSILON);
+
+        if let Some([a, b, c, d, e, f, g, h]) = self.homography {
+            let w = g.mul_add(u, h.mul_add(v, 1.0));
+
+            Point::new(a.mul_add(u, b.mul_add(v, c)) / w, d.mul_add(u, e.mul_add(v, f)) / w)
+        } else {
+            let [top_left, top_right, bottom_right, bottom_left] = self.corners;
+
+            top_left.lerp(top_right, u).lerp(bottom_left.lerp(bottom_right, u), v)
+        }
+    }
+}
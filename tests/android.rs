@@ -0,0 +1,72 @@
+use polymorpher::{
+    Morph, RoundedPolygon,
+    android::{VectorDrawable, path_data},
+    geometry::{Point, Size},
+    shapes,
+};
+
+/// Returns the commands of `path_data`, skipping their coordinates.
+fn commands(path_data: &str) -> String {
+    path_data.chars().filter(char::is_ascii_alphabetic).collect()
+}
+
+/// Returns the value of the first attribute with the given `name` in `xml`.
+fn attribute<'a>(xml: &'a str, name: &str) -> &'a str {
+    let start = xml.find(&format!("{name}=\"")).unwrap() + name.len() + 2;
+
+    &xml[start..start + xml[start..].find('"').unwrap()]
+}
+
+#[test]
+fn path_data_test() {
+    let square = RoundedPolygon::from_vertices_count(4, 1.0, None, &[]);
+    let data = path_data(square.cubics.iter().copied());
+
+    assert!(data.starts_with('M'));
+    assert_eq!(commands(&data), format!("M{}Z", "C".repeat(square.cubics.len())));
+    assert!(path_data([]).is_empty());
+}
+
+#[test]
+fn polygon_xml_test() {
+    let xml = VectorDrawable::new()
+        .with_size(Size::new(48.0, 48.0))
+        .with_viewport(Size::new(100.0, 50.0))
+        .with_fill_color(0xFF67_50A4)
+        .polygon_xml(&shapes::cookie4());
+
+    assert!(xml.starts_with("<vector"));
+    assert_eq!(attribute(&xml, "android:width"), "48dp");
+    assert_eq!(attribute(&xml, "android:viewportWidth"), "100");
+    assert_eq!(attribute(&xml, "android:fillColor"), "#FF6750A4");
+
+    // The shape is fitted into the viewport
+    let coordinates = attribute(&xml, "android:pathData")
+        .split(|c: char| c.is_ascii_alphabetic() || c == ' ' || c == ',')
+        .filter(|text| !text.is_empty())
+        .map(|text| text.parse::<f32>().unwrap())
+        .collect::<Vec<_>>();
+    let points = coordinates.chunks(2).map(|xy| Point::new(xy[0], xy[1])).collect::<Vec<_>>();
+
+    assert!(points.iter().all(|point| (25.0..=75.0).contains(&point.x) && (0.0..=50.0).contains(&point.y)));
+}
+
+#[test]
+fn morph_xml_test() {
+    let morph = Morph::new(shapes::square(), shapes::sunny());
+    let xml = VectorDrawable::new()
+        .with_duration(500)
+        .with_interpolator("@android:anim/linear_interpolator")
+        .morph_xml(&morph);
+
+    assert!(xml.starts_with("<animated-vector"));
+    assert_eq!(attribute(&xml, "android:duration"), "500");
+    assert_eq!(attribute(&xml, "android:interpolator"), "@android:anim/linear_interpolator");
+
+    let from = attribute(&xml, "android:valueFrom");
+    let to = attribute(&xml, "android:valueTo");
+
+    assert_eq!(attribute(&xml, "android:pathData"), from);
+    assert_eq!(commands(from), commands(to));
+    assert_ne!(from, to);
+}
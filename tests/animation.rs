@@ -0,0 +1,108 @@
+use float_cmp::assert_approx_eq;
+use polymorpher::{
+    Morph, RoundedPolygon,
+    animation::{MorphAnimation, Spring, SpringAnimation},
+};
+
+const EPSILON: f32 = 1e-4;
+
+fn run(animation: &mut SpringAnimation) -> (f32, usize) {
+    let mut max = animation.value();
+    let mut steps = 0;
+
+    while !animation.is_at_rest() && steps < 10_000 {
+        max = max.max(animation.update(1.0 / 120.0));
+        steps += 1;
+    }
+
+    (max, steps)
+}
+
+#[test]
+fn spring_settles_test() {
+    for damping_ratio in [0.2, 0.5, 1.0, 2.0] {
+        let mut animation = SpringAnimation::new(Spring::new(Spring::STIFFNESS_MEDIUM_LOW, damping_ratio), 0.0);
+
+        animation.animate_to(1.0);
+
+        let (max, steps) = run(&mut animation);
+
+        assert!(steps < 10_000);
+        assert_approx_eq!(f32, 1.0, animation.value(), epsilon = EPSILON);
+        assert_approx_eq!(f32, 0.0, animation.velocity(), epsilon = EPSILON);
+
+        // Only under-damped springs overshoot
+        assert_eq!(damping_ratio < 1.0, max > 1.0 + EPSILON);
+    }
+}
+
+#[test]
+fn spring_step_independence_test() {
+    let spring = Spring::EXPRESSIVE_FAST_SPATIAL;
+    let mut coarse = SpringAnimation::new(spring, 0.0);
+    let mut fine = SpringAnimation::new(spring, 0.0);
+
+    coarse.animate_to(1.0);
+    fine.animate_to(1.0);
+
+    coarse.update(0.1);
+
+    for _ in 0..10 {
+        fine.update(0.01);
+    }
+
+    assert_approx_eq!(f32, coarse.value(), fine.value(), epsilon = EPSILON);
+    assert_approx_eq!(f32, coarse.velocity(), fine.velocity(), epsilon = 1e-3);
+}
+
+#[test]
+fn spring_retarget_test() {
+    let mut animation = SpringAnimation::new(Spring::default(), 0.0);
+
+    animation.animate_to(1.0);
+    animation.update(0.05);
+
+    let (value, velocity) = (animation.value(), animation.velocity());
+
+    assert!(velocity > 0.0);
+
+    // Releasing mid-flight keeps the current state.
+    animation.animate_to(0.0);
+
+    assert_approx_eq!(f32, value, animation.value(), epsilon = EPSILON);
+    assert_approx_eq!(f32, velocity, animation.velocity(), epsilon = EPSILON);
+
+    run(&mut animation);
+
+    assert_approx_eq!(f32, 0.0, animation.value(), epsilon = EPSILON);
+}
+
+#[test]
+fn morph_overshoot_test() {
+    let morph = Morph::new(RoundedPolygon::circle().build(), RoundedPolygon::rectangle().build());
+    let start = morph.as_cubics(0.0);
+    let end = morph.as_cubics(1.0);
+    let overshoot = morph.as_cubics(1.5);
+
+    for ((start, end), overshoot) in start.iter().zip(&end).zip(&overshoot) {
+        let expected = start.control0().lerp(end.control0(), 1.5);
+
+        assert_approx_eq!(f32, expected.x, overshoot.control0().x, epsilon = EPSILON);
+        assert_approx_eq!(f32, expected.y, overshoot.control0().y, epsilon = EPSILON);
+    }
+
+    assert_eq!(overshoot.first().unwrap().anchor0(), overshoot.last().unwrap().anchor1());
+
+    let mut animation = MorphAnimation::new(morph, Spring::new(Spring::STIFFNESS_LOW, Spring::DAMPING_RATIO_HIGH_BOUNCY));
+
+    animation.animate_to(1.0);
+
+    let mut max: f32 = 0.0;
+
+    while !animation.is_at_rest() {
+        max = max.max(animation.update(1.0 / 60.0));
+    }
+
+    assert!(max > 1.0);
+    assert_approx_eq!(f32, 1.0, animation.progress(), epsilon = EPSILON);
+}
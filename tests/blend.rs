@@ -0,0 +1,64 @@
+use polymorpher::{
+    Cubic, Morph, MultiMorph, RoundedPolygon,
+    geometry::{Aabb, Point, Vector},
+};
+
+const EPSILON: f32 = 1e-3;
+
+fn aabb(cubics: &[Cubic]) -> Aabb {
+    cubics.iter().fold(Aabb::new(Point::splat(f32::MAX), Point::splat(f32::MIN)), |aabb, cubic| {
+        let cubic_aabb = cubic.aabb(false);
+
+        Aabb::new(aabb.min.min(cubic_aabb.min), aabb.max.max(cubic_aabb.max))
+    })
+}
+
+fn assert_points(expected: Point, actual: Point) {
+    assert!((expected - actual).abs().lower_than(Vector::splat(EPSILON)).all(), "{expected:?} != {actual:?}");
+}
+
+#[test]
+fn two_shapes_match_morph_test() {
+    let start = RoundedPolygon::circle().build();
+    let end = RoundedPolygon::star(5).build();
+    let morph = Morph::new(start.clone(), end.clone());
+    let blend = MultiMorph::new(vec![start, end]);
+
+    for progress in [0.0, 0.4, 1.0] {
+        let expected = morph.as_cubics(progress);
+        let actual = blend.as_cubics(&[1.0 - progress, progress]);
+
+        assert_eq!(expected.len(), actual.len());
+
+        for (expected, actual) in expected.iter().zip(&actual) {
+            assert_points(expected.anchor0(), actual.anchor0());
+            assert_points(expected.control0(), actual.control0());
+            assert_points(expected.control1(), actual.control1());
+            assert_points(expected.anchor1(), actual.anchor1());
+        }
+    }
+}
+
+#[test]
+fn bilinear_test() {
+    let shapes = vec![
+        RoundedPolygon::circle().build(),
+        RoundedPolygon::rectangle().build(),
+        RoundedPolygon::star(5).build(),
+        RoundedPolygon::pill().build(),
+    ];
+    let blend = MultiMorph::new(shapes.clone());
+
+    for (shape, (u, v)) in shapes.iter().zip([(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)]) {
+        let cubics = blend.bilinear(u, v);
+        let expected = shape.aabb(false);
+        let actual = aabb(&cubics);
+
+        assert_eq!(blend.matched_cubics(0).len(), cubics.len());
+        assert_points(expected.min, actual.min);
+        assert_points(expected.max, actual.max);
+    }
+
+    // Unnormalized weights are normalized
+    assert_eq!(blend.as_cubics(&[1.0, 1.0, 1.0, 1.0]), blend.as_cubics(&[0.25, 0.25, 0.25, 0.25]));
+}
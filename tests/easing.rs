@@ -0,0 +1,92 @@
+use float_cmp::assert_approx_eq;
+use polymorpher::{
+    Morph, RoundedPolygon,
+    easing::{self, CubicBezier, Easing, Linear, StepPosition, Steps},
+};
+
+const EPSILON: f32 = 1e-4;
+
+#[test]
+fn endpoints_test() {
+    let easings: [&dyn Easing; 5] = [&Linear, &easing::STANDARD, &easing::EMPHASIZED_DECELERATE, &easing::EASE, &easing::emphasized()];
+
+    for easing in easings {
+        assert_approx_eq!(f32, 0.0, easing.ease(0.0), epsilon = EPSILON);
+        assert_approx_eq!(f32, 1.0, easing.ease(1.0), epsilon = EPSILON);
+    }
+}
+
+#[test]
+fn linear_cubic_bezier_test() {
+    let easing = CubicBezier::new(1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0);
+
+    for i in 0..=100u8 {
+        let x = f32::from(i) / 100.0;
+
+        assert_approx_eq!(f32, x, easing.ease(x), epsilon = EPSILON);
+    }
+}
+
+#[test]
+fn cubic_bezier_test() {
+    // Reference values of the CSS `ease` timing function
+    assert_approx_eq!(f32, 0.409_4, easing::EASE.ease(0.25), epsilon = 1e-3);
+    assert_approx_eq!(f32, 0.802_2, easing::EASE.ease(0.5), epsilon = 1e-3);
+    assert_approx_eq!(f32, 0.960_2, easing::EASE.ease(0.75), epsilon = 1e-3);
+
+    // `ease-in-out` is symmetric
+    for i in 0..=50u8 {
+        let x = f32::from(i) / 100.0;
+
+        assert_approx_eq!(f32, 1.0 - easing::EASE_IN_OUT.ease(x), easing::EASE_IN_OUT.ease(1.0 - x), epsilon = EPSILON);
+    }
+}
+
+#[test]
+fn emphasized_test() {
+    let emphasized = easing::emphasized();
+
+    assert_approx_eq!(f32, 0.4, emphasized.ease(0.166_666), epsilon = 1e-3);
+
+    let mut previous = 0.0;
+
+    for i in 0..=100u8 {
+        let value = emphasized.ease(f32::from(i) / 100.0);
+
+        assert!(value >= previous - EPSILON);
+
+        previous = value;
+    }
+}
+
+#[test]
+fn steps_test() {
+    let end = Steps::new(4, StepPosition::JumpEnd);
+
+    assert_approx_eq!(f32, 0.0, end.ease(0.1), epsilon = EPSILON);
+    assert_approx_eq!(f32, 0.25, end.ease(0.3), epsilon = EPSILON);
+    assert_approx_eq!(f32, 1.0, end.ease(1.0), epsilon = EPSILON);
+
+    let start = Steps::new(4, StepPosition::JumpStart);
+
+    assert_approx_eq!(f32, 0.25, start.ease(0.1), epsilon = EPSILON);
+
+    let none = Steps::new(5, StepPosition::JumpNone);
+
+    assert_approx_eq!(f32, 0.0, none.ease(0.1), epsilon = EPSILON);
+    assert_approx_eq!(f32, 0.25, none.ease(0.3), epsilon = EPSILON);
+
+    let both = Steps::new(3, StepPosition::JumpBoth);
+
+    assert_approx_eq!(f32, 0.25, both.ease(0.0), epsilon = EPSILON);
+    assert_approx_eq!(f32, 0.75, both.ease(0.7), epsilon = EPSILON);
+}
+
+#[test]
+fn eased_morph_test() {
+    let morph = Morph::new(RoundedPolygon::circle().build(), RoundedPolygon::star(4).build());
+    let eased = morph.clone().eased(easing::STANDARD);
+
+    assert_eq!(morph.as_cubics(easing::STANDARD.ease(0.3)), eased.as_cubics(0.3));
+    assert_eq!(morph.as_cubics(1.0), eased.as_cubics(1.0));
+}
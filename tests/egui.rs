@@ -0,0 +1,124 @@
+#![cfg(feature = "egui")]
+
+use egui::{Color32, Mesh, Stroke};
+use polymorpher::{
+    CornerRounding, Morph, RoundedPolygon,
+    egui::{fill_mesh, shape},
+    geometry::Point,
+};
+
+/// Returns the areas of the triangles of the `mesh` made of the first `count`
+/// vertices.
+fn triangle_areas(mesh: &Mesh, count: usize) -> Vec<f32> {
+    mesh.indices
+        .chunks(3)
+        .filter(|triangle| triangle.iter().all(|&i| (i as usize) < count))
+        .map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize].pos);
+
+            (b - a).x.mul_add((c - a).y, -((b - a).y * (c - a).x)) / 2.0
+        })
+        .collect()
+}
+
+fn area(points: &[Point]) -> f32 {
+    (0..points.len())
+        .map(|i| points[i].to_vector().cross(points[(i + 1) % points.len()].to_vector()) / 2.0)
+        .sum()
+}
+
+#[test]
+fn fill_mesh_test() {
+    let star = RoundedPolygon::star(5).with_inner_radius(0.5).build();
+    let points = star.flattened(0.01);
+    let mesh = fill_mesh(&points, Color32::RED, 0.0);
+    let n = points.len();
+
+    assert_eq!(mesh.vertices.len(), n);
+    assert_eq!(mesh.indices.len(), (n - 2) * 3);
+
+    // Triangles don't overlap, even though the star is concave
+    let areas = triangle_areas(&mesh, n);
+
+    assert!(areas.iter().all(|&area| area >= 0.0));
+    assert!((areas.iter().sum::<f32>() - area(&points)).abs() < 1e-4);
+    assert!(mesh.vertices.iter().all(|vertex| vertex.color == Color32::RED));
+
+    let feathered = fill_mesh(&points, Color32::RED, 0.5);
+
+    assert_eq!(feathered.vertices.len(), n * 2);
+    assert_eq!(feathered.indices.len(), (n - 2) * 3 + n * 6);
+    assert_eq!(feathered.vertices.last().unwrap().color, Color32::TRANSPARENT);
+
+    // Outer vertices are pushed outwards, away from the center, for outlines
+    // winding either way
+    let reversed = points.iter().rev().copied().collect::<Vec<_>>();
+
+    for points in [&points, &reversed] {
+        let feathered = fill_mesh(points, Color32::RED, 0.5);
+
+        for i in 0..n {
+            let inner = feathered.vertices[i].pos;
+            let outer = feathered.vertices[n + i].pos;
+            let center = Point::new(star.center.x, star.center.y);
+
+            assert!((Point::new(outer.x, outer.y) - center).length() > (Point::new(inner.x, inner.y) - center).length());
+        }
+    }
+}
+
+#[test]
+fn fill_mesh_concave_test() {
+    // A `C` whose center lies in its opening, which a fan from it can't fill
+    let vertices = [
+        Point::new(0.0, 0.0),
+        Point::new(3.0, 0.0),
+        Point::new(3.0, 1.0),
+        Point::new(1.0, 1.0),
+        Point::new(1.0, 2.0),
+        Point::new(3.0, 2.0),
+        Point::new(3.0, 3.0),
+        Point::new(0.0, 3.0),
+    ];
+    let polygon = RoundedPolygon::from_vertices(&vertices, CornerRounding::new(0.2), &[], Point::splat(1.5));
+
+    for points in [polygon.flattened(0.01), polygon.flattened(0.01).into_iter().rev().collect()] {
+        let mesh = fill_mesh(&points, Color32::RED, 0.0);
+        let areas = triangle_areas(&mesh, points.len());
+        let expected = area(&points);
+
+        assert_eq!(areas.len(), points.len() - 2);
+        assert!(areas.iter().all(|area| area * expected.signum() >= 0.0));
+        assert!((areas.iter().sum::<f32>() - expected).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn shape_test() {
+    let morph = Morph::new(RoundedPolygon::circle().build(), RoundedPolygon::star(4).build());
+    let frame = morph.frame(0.5);
+
+    let egui::Shape::Vec(shapes) = frame.to_egui_shape(Color32::RED, Stroke::new(1.0, Color32::BLACK), 1.0) else {
+        panic!("Expected a list of shapes");
+    };
+
+    assert_eq!(shapes.len(), 2);
+
+    let egui::Shape::Vec(shapes) = shape(&frame.flattened(0.01), Color32::TRANSPARENT, Stroke::NONE, 1.0) else {
+        panic!("Expected a list of shapes");
+    };
+
+    assert!(shapes.is_empty());
+
+    // Frames midway through a transition between concave shapes are filled
+    // without overlaps
+    let morph = Morph::new(polymorpher::shapes::very_sunny(), polymorpher::shapes::clover4());
+
+    for progress in [0.25, 0.5, 0.75] {
+        let points = morph.frame(progress).flattened(1e-3);
+        let mesh = fill_mesh(&points, Color32::RED, 0.0);
+        let areas = triangle_areas(&mesh, points.len());
+
+        assert!((areas.iter().map(|area| area.abs()).sum::<f32>() - area(&points).abs()).abs() < 1e-4);
+    }
+}
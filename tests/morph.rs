@@ -1,6 +1,8 @@
 use polymorpher::{
-    Morph, RoundedPolygon,
-    geometry::{Point, Vector},
+    Cubic, Fit, Morph, MorphBatch, RoundedPolygon,
+    geometry::{Aabb, Point, Size, Vector},
+    path::{CubicsBuilder, PathBuilder, PathEvent, PathEvents, PathOptions, add_events},
+    stagger::{AngularStagger, OutlineStagger, Stagger},
 };
 
 const EPSILON: f32 = 1e-4;
@@ -40,3 +42,236 @@ fn cubics_test() {
         assert!(matched);
     }
 }
+
+#[test]
+fn cubics_at_test() {
+    let morph = Morph::new(
+        RoundedPolygon::from_vertices_count_at(3, 1.0, Point::new(0.5, 0.5), None, &[]),
+        RoundedPolygon::star(5).with_center(Point::new(0.5, 0.5)).build(),
+    );
+    let mut cubics = Vec::new();
+
+    for progress in [0.0, 0.25, 0.5, 0.75, 1.0] {
+        let expected = morph.as_cubics(progress);
+
+        morph.as_cubics_into(progress, &mut cubics);
+
+        assert_eq!(expected, cubics);
+        assert_eq!(expected, morph.cubics_at(progress).collect::<Vec<_>>());
+        assert_eq!(expected.len(), morph.cubics_at(progress).len());
+        assert_eq!(expected.first().unwrap().anchor0(), expected.last().unwrap().anchor1());
+    }
+}
+
+#[test]
+fn batch_test() {
+    let morphs = [
+        Morph::new(RoundedPolygon::circle().build(), RoundedPolygon::star(5).build()),
+        Morph::new(RoundedPolygon::rectangle().build(), RoundedPolygon::pill().build()),
+    ];
+    let batch = MorphBatch::new(&morphs);
+
+    assert_eq!(2, batch.len());
+
+    for frame in batch.bake(&[0.0, 0.3, 1.0]) {
+        for (index, morph) in morphs.iter().enumerate() {
+            let expected = morph.as_cubics(frame.progress());
+            let actual = frame.as_cubics(index);
+
+            assert_eq!(expected.len(), actual.len());
+
+            for (expected, actual) in expected.iter().zip(&actual) {
+                for (expected, actual) in [
+                    (expected.anchor0(), actual.anchor0()),
+                    (expected.control0(), actual.control0()),
+                    (expected.control1(), actual.control1()),
+                    (expected.anchor1(), actual.anchor1()),
+                ] {
+                    assert!((expected - actual).abs().lower_than(Vector::splat(EPSILON)).all());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn bounds_test() {
+    let morph = Morph::new(
+        RoundedPolygon::rectangle().with_size(Size::new(4.0, 1.0)).build(),
+        RoundedPolygon::rectangle().with_size(Size::new(1.0, 4.0)).build(),
+    );
+
+    for approximate in [true, false] {
+        let bounds = morph.bounds(0.0..=1.0, approximate);
+
+        assert!(bounds.min.x <= -2.0 + EPSILON && bounds.max.x >= 2.0 - EPSILON);
+        assert!(bounds.min.y <= -2.0 + EPSILON && bounds.max.y >= 2.0 - EPSILON);
+
+        // Every intermediate state is contained in the bounds
+        for i in 0..=20u8 {
+            let aabb = morph.aabb(f32::from(i) / 20.0, false);
+
+            assert!(bounds.min.x <= aabb.min.x + EPSILON && bounds.min.y <= aabb.min.y + EPSILON);
+            assert!(bounds.max.x >= aabb.max.x - EPSILON && bounds.max.y >= aabb.max.y - EPSILON);
+        }
+
+        // Overshooting grows the bounds
+        let overshoot = morph.bounds(-0.1..=1.1, approximate);
+
+        assert!(overshoot.width() > bounds.width() && overshoot.height() > bounds.height());
+    }
+}
+
+#[test]
+fn staggered_test() {
+    let morph = Morph::new(RoundedPolygon::circle().build(), RoundedPolygon::star(6).build());
+    let staggers = [OutlineStagger::new(0.0, 0.5), OutlineStagger::new(0.25, 0.8).symmetric()];
+
+    for stagger in staggers {
+        for progress in [0.0, 0.3, 0.6, 1.0] {
+            let cubics = morph.as_cubics_staggered(progress, &stagger);
+
+            assert_eq!(morph.segments().len(), cubics.len());
+
+            // The outline stays continuous and closed
+            for i in 0..cubics.len() {
+                assert_eq!(cubics[i].anchor1(), cubics[(i + 1) % cubics.len()].anchor0());
+            }
+        }
+
+        // Staggering doesn't affect the start and end states
+        for progress in [0.0, 1.0] {
+            for (expected, actual) in morph.as_cubics(progress).iter().zip(morph.as_cubics_staggered(progress, &stagger)) {
+                assert!((expected.control0() - actual.control0()).abs().lower_than(Vector::splat(EPSILON)).all());
+                assert!((expected.anchor1() - actual.anchor1()).abs().lower_than(Vector::splat(EPSILON)).all());
+            }
+        }
+    }
+
+    // Segments at the origin finish earlier than the opposite ones
+    let stagger = AngularStagger::new(0.0, 0.5);
+    let first = morph.segments().iter().min_by(|a, b| a.angle.total_cmp(&b.angle)).unwrap();
+    let last = morph.segments().iter().max_by(|a, b| a.angle.total_cmp(&b.angle)).unwrap();
+
+    assert!(stagger.progress(0.5, first) > stagger.progress(0.5, last));
+}
+
+#[test]
+fn fit_into_test() {
+    let start = RoundedPolygon::rectangle().with_size(Size::new(4.0, 1.0)).build();
+    let end = RoundedPolygon::rectangle().with_size(Size::new(1.0, 4.0)).build();
+    let rect = Aabb::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+    let morph = Morph::new(start, end).fit_into(rect, Fit::default());
+
+    // The bounds of the whole transition fit into the rectangle
+    let bounds = morph.bounds(0.0..=1.0, true);
+
+    assert!((bounds.min - rect.min).length() < EPSILON);
+    assert!((bounds.max - rect.max).length() < EPSILON);
+
+    // Every state is scaled the same way
+    assert!((morph.aabb(0.0, true).size() - Size::new(2.0, 0.5)).to_vector().length() < EPSILON);
+    assert!((morph.aabb(1.0, true).size() - Size::new(0.5, 2.0)).to_vector().length() < EPSILON);
+}
+
+#[test]
+fn path_events_test() {
+    let square = RoundedPolygon::rectangle().with_size(Size::new(2.0, 2.0)).build();
+    let star = RoundedPolygon::star(5).build();
+    let events = square.path_events().collect::<Vec<_>>();
+
+    assert_eq!(events.len(), square.cubics.len() + 2);
+    assert_eq!(events[0], PathEvent::MoveTo(square.cubics[0].anchor0()));
+    assert_eq!(events.last(), Some(&PathEvent::Close));
+
+    let mut builder = CubicsBuilder::new();
+
+    add_events(&mut builder, square.path_events());
+
+    assert_eq!(builder.build(), vec![square.cubics.clone()]);
+
+    let morph = Morph::new(square, star);
+    let offset = |point: Point| point + Vector::new(1.0, 0.0);
+    let mut builder = CubicsBuilder::new();
+
+    add_events(&mut builder, morph.path_events(0.5).map(|event| event.transformed(&offset)));
+
+    let expected = morph.as_cubics(0.5).into_iter().map(|cubic| cubic.transformed(&offset)).collect::<Vec<_>>();
+
+    assert_eq!(builder.build(), vec![expected]);
+    assert!(morph.frame(0.5).path_events().eq(morph.path_events(0.5)));
+}
+
+#[test]
+fn path_options_test() {
+    let square = RoundedPolygon::rectangle().with_size(Size::new(2.0, 2.0)).build();
+    let lines = square.path_events_with(PathOptions::new().straight_lines()).collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 6);
+    assert!(lines[1..5].iter().all(|event| matches!(event, PathEvent::LineTo(_))));
+
+    // Reversing keeps the start point, but flips the winding direction
+    let reversed = square.as_path_with::<CubicsBuilder>(PathOptions::new().reversed());
+    let expected = square.cubics.iter().rev().map(|cubic| cubic.reversed()).collect::<Vec<_>>();
+
+    assert_eq!(reversed, vec![expected]);
+
+    let shifted = square.as_path_with::<CubicsBuilder>(PathOptions::new().starting_at(1.125)).remove(0);
+
+    assert_eq!(shifted.len(), square.cubics.len() + 1);
+    assert!((shifted[0].anchor0() - Point::new(0.0, 1.0)).length() < EPSILON);
+    assert_eq!(shifted[0].anchor0(), shifted.last().unwrap().anchor1());
+
+    let point = Point::new(1.0, 0.0);
+    let cubics = [Cubic::straight_line(Point::zero(), point), Cubic::straight_line(point, point)];
+    let events = PathEvents::with_options(cubics, PathOptions::new().skip_degenerate());
+
+    assert!(events.eq([
+        PathEvent::MoveTo(Point::zero()),
+        PathEvent::CubicTo {
+            ctrl1: cubics[0].control0(),
+            ctrl2: cubics[0].control1(),
+            to: point
+        },
+        PathEvent::Close
+    ]));
+
+    let morph = Morph::new(square, RoundedPolygon::star(5).build());
+
+    assert!(morph.path_events_with(0.3, PathOptions::new()).eq(morph.path_events(0.3)));
+}
+
+#[cfg(feature = "kurbo")]
+#[test]
+fn kurbo_shape_test() {
+    use kurbo::Shape;
+
+    let square = RoundedPolygon::rectangle().with_size(Size::new(2.0, 2.0)).build();
+    let circle = RoundedPolygon::circle().with_radius(1.0).build();
+
+    assert!((square.area().abs() - 4.0).abs() < 1e-3);
+    assert!((square.perimeter(1e-6) - 8.0).abs() < 1e-3);
+    assert!(square.contains(kurbo::Point::ZERO));
+    assert!(!square.contains(kurbo::Point::new(1.5, 0.0)));
+    assert_eq!(square.bounding_box().round(), kurbo::Rect::new(-1.0, -1.0, 1.0, 1.0));
+    assert_eq!(square.to_path(0.1).elements().len(), square.cubics.len() + 2);
+
+    let morph = Morph::new(square.clone(), circle.clone());
+
+    assert!((morph.frame(0.0).area() - square.area()).abs() < 1e-3);
+    assert!((morph.frame(1.0).area() - circle.area()).abs() < 1e-3);
+    assert!((morph.frame(1.0).area().abs() - core::f64::consts::PI).abs() < 1e-2);
+    assert!(morph.frame(0.5).contains(kurbo::Point::ZERO));
+
+    // Winding numbers match the ones of the equivalent kurbo path
+    let star = polymorpher::shapes::very_sunny();
+    let burst = Morph::new(polymorpher::shapes::cookie7(), polymorpher::shapes::soft_burst());
+    let frame = burst.frame(0.4);
+
+    for (x, y) in (0..=20).flat_map(|x| (0..=20).map(move |y| (f64::from(x) / 20.0, f64::from(y) / 20.0))) {
+        let point = kurbo::Point::new(x, y);
+
+        assert_eq!(star.winding(point), star.to_path(0.1).winding(point));
+        assert_eq!(frame.winding(point), frame.to_path(0.1).winding(point));
+    }
+}
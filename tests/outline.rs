@@ -0,0 +1,240 @@
+use float_cmp::assert_approx_eq;
+use polymorpher::{ArcLengthMeasurer, CornerRounding, Cubic, Dasher, MeasuredPolygon, Measurer, Morph, OutlineSample, RoundedPolygon, geometry::Point};
+
+const EPSILON: f32 = 1e-3;
+
+#[test]
+fn sample_test() {
+    let circle = RoundedPolygon::circle().build().measured();
+
+    for progress in [0.0, 0.1, 0.35, 0.5, 0.99, 1.25, -0.25] {
+        let sample = circle.sample(progress);
+
+        assert_approx_eq!(f32, sample.point.to_vector().length(), 1.0, epsilon = EPSILON);
+        assert_approx_eq!(f32, sample.tangent.length(), 1.0, epsilon = EPSILON);
+        assert_approx_eq!(f32, sample.tangent.dot(sample.point.to_vector()), 0.0, epsilon = EPSILON);
+        assert_approx_eq!(f32, sample.normal.dot(sample.point.to_vector()), 1.0, epsilon = EPSILON);
+        assert_approx_eq!(f32, sample.curvature, 1.0, epsilon = 0.05);
+        assert_eq!(sample.point, circle.point_at(progress));
+    }
+
+    // Progress wraps around
+    assert_eq!(circle.sample(0.25), circle.sample(1.25));
+
+    // Straight edges have no curvature
+    let square = RoundedPolygon::from_vertices_count(4, 1.0, None, &[]).measured();
+
+    assert_approx_eq!(f32, square.sample(0.125).curvature, 0.0, epsilon = EPSILON);
+}
+
+#[test]
+fn progress_of_closest_test() {
+    let shape = RoundedPolygon::star(5).build().measured();
+
+    for progress in [0.0, 0.05, 0.3, 0.5, 0.77, 0.9] {
+        let point = shape.point_at(progress);
+        let closest = shape.progress_of_closest(point);
+        let distance = (closest - progress).abs();
+
+        assert!(distance.min(1.0 - distance) < EPSILON, "{progress} != {closest}");
+    }
+
+    // Points off the outline map to the closest point on it
+    let square = RoundedPolygon::from_vertices_count(4, 1.0, None, &[]).measured();
+    let progress = square.progress_of_closest(Point::new(2.0, 2.0));
+
+    assert!((square.point_at(progress) - Point::new(0.5, 0.5)).length() < EPSILON);
+}
+
+#[test]
+fn morph_measured_test() {
+    let morph = Morph::new(RoundedPolygon::circle().build(), RoundedPolygon::circle().with_radius(2.0).build());
+    let measured = morph.measured(0.5);
+
+    for progress in [0.0, 0.2, 0.6] {
+        assert_approx_eq!(f32, measured.point_at(progress).to_vector().length(), 1.5, epsilon = EPSILON);
+    }
+}
+
+fn assert_points(expected: Point, actual: Point) {
+    assert!((expected - actual).length() < EPSILON, "{expected:?} != {actual:?}");
+}
+
+fn assert_continuous(cubics: &[Cubic]) {
+    for pair in cubics.windows(2) {
+        assert_points(pair[0].anchor1(), pair[1].anchor0());
+    }
+}
+
+#[test]
+fn trimmed_test() {
+    let circle = RoundedPolygon::circle().build();
+    let measured = circle.measured();
+
+    let quarter = circle.trimmed(0.0, 0.25, 0.0);
+
+    assert_continuous(&quarter);
+    assert_points(measured.point_at(0.0), quarter[0].anchor0());
+    assert_points(measured.point_at(0.25), quarter[quarter.len() - 1].anchor1());
+
+    // Start and end are swapped if needed, and shifted by the offset
+    let shifted = circle.trimmed(0.2, 0.1, 0.5);
+
+    assert_continuous(&shifted);
+    assert_points(measured.point_at(0.6), shifted[0].anchor0());
+    assert_points(measured.point_at(0.7), shifted[shifted.len() - 1].anchor1());
+
+    // The trimmed part wraps across 0
+    let wrapped = circle.trimmed(0.0, 0.3, 0.85);
+
+    assert_continuous(&wrapped);
+    assert_points(measured.point_at(0.85), wrapped[0].anchor0());
+    assert_points(measured.point_at(0.15), wrapped[wrapped.len() - 1].anchor1());
+
+    // The full outline ends where it starts
+    let full = circle.trimmed(0.0, 1.0, 0.4);
+
+    assert_continuous(&full);
+    assert_points(full[0].anchor0(), full[full.len() - 1].anchor1());
+
+    assert!(circle.trimmed(0.5, 0.5, 0.0).is_empty());
+
+    let morph = Morph::new(circle.clone(), circle);
+
+    assert_eq!(morph.trimmed(0.5, 0.0, 0.25, 0.0), morph.measured(0.5).trimmed(0.0, 0.25, 0.0));
+}
+
+fn dash_length(dash: &[Cubic]) -> f32 {
+    dash.iter().map(|cubic| (cubic.anchor1() - cubic.anchor0()).length()).sum()
+}
+
+#[test]
+fn dasher_test() {
+    let square = RoundedPolygon::from_vertices(
+        &[Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 1.0), Point::new(0.0, 1.0)],
+        CornerRounding::UNROUNDED,
+        &[],
+        Point::new(0.5, 0.5),
+    )
+    .measured();
+
+    let dashes = Dasher::new(&[0.5, 0.5], 0.0).dashes(&square);
+
+    assert_eq!(dashes.len(), 4);
+
+    for dash in &dashes {
+        assert_approx_eq!(f32, dash_length(dash), 0.5, epsilon = EPSILON);
+    }
+
+    assert_points(Point::new(0.0, 0.0), dashes[0][0].anchor0());
+
+    // The dash crossing the start of the outline is joined into one
+    let dashes = Dasher::new(&[0.5, 0.5], 0.25).dashes(&square);
+
+    assert_eq!(dashes.len(), 4);
+
+    let last = &dashes[3];
+
+    assert_continuous(last);
+    assert_approx_eq!(f32, dash_length(last), 0.5, epsilon = EPSILON);
+    assert_points(Point::new(0.0, 0.25), last[0].anchor0());
+    assert_points(Point::new(0.25, 0.0), last[last.len() - 1].anchor1());
+
+    // Odd patterns are repeated
+    let odd = Dasher::new(&[1.0], 0.0);
+
+    assert_eq!(odd.pattern(), [1.0, 1.0]);
+    assert_eq!(odd.dashes(&square).len(), 2);
+}
+
+#[test]
+fn dasher_circle_test() {
+    use core::f32::consts::PI;
+
+    let circle = RoundedPolygon::circle().with_radius(1.0).build();
+    let measurer = ArcLengthMeasurer::new(1e-5);
+    let length = circle.cubics.iter().map(|cubic| measurer.measure_cubic(cubic)).sum::<f32>();
+
+    assert_approx_eq!(f32, length, 2.0 * PI, epsilon = EPSILON);
+
+    // Dashes are laid out by arc length, so they span equal angles on a circle
+    let dash = PI / 8.0;
+    let dashes = Dasher::new(&[dash, dash], 0.0).dashes(&MeasuredPolygon::measure_polygon(measurer, &circle));
+    let angle = |point: Point| point.y.atan2(point.x);
+
+    assert_eq!(dashes.len(), 8);
+
+    for (i, cubics) in dashes.iter().enumerate() {
+        let (start, end) = (cubics[0].anchor0(), cubics[cubics.len() - 1].anchor1());
+        let arc_length = cubics.iter().map(|cubic| measurer.measure_cubic(cubic)).sum::<f32>();
+
+        assert_approx_eq!(f32, arc_length, dash, epsilon = EPSILON);
+        assert_approx_eq!(f32, (angle(end) - angle(start)).rem_euclid(2.0 * PI), dash, epsilon = EPSILON);
+
+        if let Some(next) = dashes.get(i + 1) {
+            assert_approx_eq!(f32, (angle(next[0].anchor0()) - angle(end)).rem_euclid(2.0 * PI), dash, epsilon = EPSILON);
+        }
+    }
+
+    // Polygons and morphs are measured by arc length as well
+    let pattern = Dasher::new(&[dash, dash], 0.0);
+    let morph = Morph::new(circle.clone(), RoundedPolygon::rectangle().build());
+
+    assert_eq!(
+        pattern.polygon_dashes(&circle),
+        pattern.dashes(&MeasuredPolygon::measure_polygon(ArcLengthMeasurer::default(), &circle))
+    );
+    assert_eq!(pattern.polygon_dashes(&circle).len(), 8);
+    assert_eq!(
+        pattern.morph_dashes(&morph, 0.5),
+        pattern.dashes(&MeasuredPolygon::measure_cubics(ArcLengthMeasurer::default(), &morph.as_cubics(0.5)))
+    );
+}
+
+#[test]
+fn resample_test() {
+    let square = RoundedPolygon::from_vertices(
+        &[Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 1.0), Point::new(0.0, 1.0)],
+        CornerRounding::UNROUNDED,
+        &[],
+        Point::new(0.5, 0.5),
+    );
+
+    let samples = square.resample(8, false);
+
+    assert_eq!(samples.len(), 8);
+
+    for i in 0..8 {
+        let distance = (samples[(i + 1) % 8].point - samples[i].point).length();
+
+        assert_approx_eq!(f32, distance, 0.5, epsilon = EPSILON);
+    }
+
+    // Without snapping, 6 samples miss some of the corners...
+    let has_vertex = |samples: &[OutlineSample], vertex: Point| samples.iter().any(|sample| (sample.point - vertex).length() < EPSILON);
+    let vertices = [Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 1.0), Point::new(0.0, 1.0)];
+    let samples = square.resample(6, false);
+
+    assert!(!vertices.iter().all(|&vertex| has_vertex(&samples, vertex)));
+
+    // ...but snapping places a sample onto each of them
+    let samples = square.resample(6, true);
+
+    assert_eq!(samples.len(), 6);
+    assert!(vertices.iter().all(|&vertex| has_vertex(&samples, vertex)));
+
+    let morph = Morph::new(square.clone(), square);
+
+    assert_eq!(morph.resample(0.5, 8).len(), 8);
+
+    // Curved outlines are sampled by their real arc length
+    let cookie = polymorpher::shapes::cookie4();
+    let exact = MeasuredPolygon::measure_polygon(ArcLengthMeasurer::new(1e-6), &cookie);
+    let morph = Morph::new(cookie.clone(), cookie.clone());
+
+    for samples in [cookie.resample(64, false), morph.resample(0.0, 64)] {
+        for (i, sample) in (0..64u8).zip(&samples) {
+            assert!((exact.sample(f32::from(i) / 64.0).point - sample.point).length() < 1e-4);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "tiny-skia-render")]
+
+use polymorpher::{
+    Morph, RoundedPolygon,
+    render::{fill_morph, fill_polygon, render_catalog, save_catalog_png, stroke_polygon},
+    shapes,
+};
+use tiny_skia::{Color, Paint, Pixmap, Stroke, Transform};
+
+fn paint() -> Paint<'static> {
+    let mut paint = Paint::default();
+
+    paint.set_color(Color::BLACK);
+
+    paint
+}
+
+fn alpha(pixmap: &Pixmap, x: u32, y: u32) -> u8 {
+    pixmap.pixel(x, y).unwrap().alpha()
+}
+
+#[test]
+fn fill_and_stroke_test() {
+    let square = RoundedPolygon::rectangle().build().normalized();
+    let transform = Transform::from_scale(48.0, 48.0).post_translate(8.0, 8.0);
+    let mut pixmap = Pixmap::new(64, 64).unwrap();
+
+    fill_polygon(&mut pixmap.as_mut(), &square, &paint(), transform);
+
+    assert_eq!(alpha(&pixmap, 32, 32), 255);
+    assert_eq!(alpha(&pixmap, 4, 4), 0);
+
+    let mut pixmap = Pixmap::new(64, 64).unwrap();
+
+    stroke_polygon(
+        &mut pixmap.as_mut(),
+        &square,
+        &paint(),
+        &Stroke {
+            // Strokes are transformed too
+            width: 2.0 / 48.0,
+            ..Stroke::default()
+        },
+        transform,
+    );
+
+    assert_eq!(alpha(&pixmap, 32, 32), 0);
+    assert_eq!(alpha(&pixmap, 32, 8), 255);
+
+    // A circle morphing into a square covers the corners only at the end
+    let morph = Morph::new(shapes::circle(None), square);
+
+    for (progress, expected) in [(0.0, 0), (1.0, 255)] {
+        let mut pixmap = Pixmap::new(64, 64).unwrap();
+
+        fill_morph(&mut pixmap.as_mut(), &morph, progress, &paint(), transform);
+
+        assert_eq!(alpha(&pixmap, 32, 32), 255);
+        assert_eq!(alpha(&pixmap, 10, 10), expected);
+    }
+}
+
+#[test]
+fn catalog_test() {
+    let catalog = render_catalog(32, 6, &paint(), Color::WHITE).unwrap();
+    let rows = u32::try_from(shapes::all().len().div_ceil(6)).unwrap();
+
+    assert_eq!((catalog.width(), catalog.height()), (192, 32 * rows));
+    // The first cell contains a circle
+    assert_eq!(catalog.pixel(16, 16).unwrap().red(), 0);
+    assert_eq!(catalog.pixel(1, 1).unwrap().red(), 255);
+    assert!(render_catalog(0, 6, &paint(), Color::WHITE).is_none());
+
+    let path = std::env::temp_dir().join("polymorpher_catalog_test.png");
+
+    save_catalog_png(&path, 16, 8).unwrap();
+
+    assert!(std::fs::read(&path).unwrap().starts_with(b"\x89PNG"));
+
+    std::fs::remove_file(path).unwrap();
+}
@@ -1,7 +1,8 @@
 use float_cmp::assert_approx_eq;
 use polymorpher::{
-    CornerRounding, Cubic, Feature, RoundedPolygon,
-    geometry::{Point, Vector},
+    Alignment, CornerRounding, Cubic, Feature, Fit, FitMode, ParametricMorph, RoundedPolygon,
+    geometry::{Aabb, Angle, Matrix2, Point, PointTransformer, Size, TransformExt, Vector},
+    path::{CubicsBuilder, PathBuilder},
 };
 
 const ROUNDING: CornerRounding = CornerRounding::new(1.0);
@@ -355,3 +356,633 @@ fn do_uneven_smooth_test(rounding0: CornerRounding, expected_v0_sx: f32, expecte
     assert_approx_eq!(f32, expected_v0_sy, e30.cubics[0].anchor1().y, epsilon = EPSILON);
     assert_approx_eq!(f32, expected_v3_sy, 1.0 - e30.cubics[0].anchor0().y, epsilon = EPSILON);
 }
+
+#[test]
+fn source_test() {
+    let polygon = RoundedPolygon::from_vertices_count(4, 1.0, Some(ROUNDING), &[]);
+    let source = polygon.source().unwrap();
+
+    assert_eq!(source.vertices.len(), 4);
+    assert_eq!(source.roundings, PER_VERTEX);
+    assert_polygons(&polygon, &source.build());
+
+    // Radii follow the scale of similarity transformations
+    let scaled = polygon.clone().transformed_affine(&Matrix2::scale(2.0, 2.0));
+    let source = scaled.source().unwrap();
+
+    assert_approx_eq!(f32, source.roundings[0].radius, 2.0, epsilon = EPSILON);
+    assert_points(Point::new(2.0, 0.0), source.vertices[0]);
+    assert_polygons(&scaled, &source.build());
+
+    // Other transformations don't keep a source, which would describe a different
+    // shape
+    assert!(polygon.clone().transformed_affine(&Matrix2::scale(2.0, 1.0)).source().is_none());
+    assert!(polygon.clone().transformed(|point: Point| point * 2.0).source().is_none());
+    assert!(polygon.clone().transformed_adaptive(|point: Point| point * 2.0, 0.01).source().is_none());
+
+    // The source doesn't affect equality
+    assert_eq!(scaled, RoundedPolygon::from_features(scaled.features.clone(), Some(scaled.center)));
+
+    // Polygons created from features do not remember a source
+    assert!(RoundedPolygon::from_features(scaled.features, None).source().is_none());
+}
+
+#[test]
+fn parametric_morph_test() {
+    let start = RoundedPolygon::from_vertices_count(4, 1.0, Some(CornerRounding::new(0.2)), &[]);
+    let end = RoundedPolygon::from_vertices_count(4, 2.0, Some(CornerRounding::new(0.8)), &[]);
+    let morph = ParametricMorph::new(start.clone(), end.clone());
+
+    assert_polygons(&start, &morph.as_polygon(0.0));
+    assert_polygons(&end, &morph.as_polygon(1.0));
+
+    let middle = RoundedPolygon::from_vertices_count(4, 1.5, Some(CornerRounding::new(0.5)), &[]);
+
+    assert_polygons(&middle, &morph.as_polygon(0.5));
+    assert_eq!(middle.cubics, morph.as_cubics(0.5));
+
+    // Shapes with a different number of vertices can't be morphed parametrically
+    let triangle = RoundedPolygon::from_vertices_count(3, 1.0, None, &[]);
+
+    assert!(!ParametricMorph::is_compatible(&start, &triangle));
+    assert!(!ParametricMorph::is_compatible(&start, &RoundedPolygon::from_features(end.features, None)));
+}
+
+#[test]
+fn editing_test() {
+    let square = RoundedPolygon::from_vertices_count(4, 1.0, None, &[]);
+
+    let moved = square.clone().with_vertex_moved(0, Point::new(2.0, 0.0));
+
+    assert_points(Point::new(2.0, 0.0), moved.source().unwrap().vertices[0]);
+    assert_approx_eq!(f32, moved.aabb(false).max.x, 2.0, epsilon = EPSILON);
+
+    let rounded = square.clone().with_rounding_at(1, ROUNDING);
+
+    assert_eq!(rounded.source().unwrap().roundings[1], ROUNDING);
+    assert_eq!(rounded.source().unwrap().roundings[0], CornerRounding::UNROUNDED);
+
+    let pentagon = square.clone().insert_vertex(1, Point::new(1.0, 1.0), ROUNDING);
+
+    assert_eq!(pentagon.source().unwrap().vertices.len(), 5);
+    assert_points(Point::new(1.0, 1.0), pentagon.source().unwrap().vertices[1]);
+    assert_polygons(&square, &pentagon.remove_vertex(1));
+}
+
+#[test]
+fn editing_center_test() {
+    let vertices = [Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 1.0), Point::new(0.0, 1.0)];
+    let square = RoundedPolygon::from_vertices(&vertices, CornerRounding::UNROUNDED, &[], Point::splat(f32::MIN));
+
+    assert_eq!(square.source().unwrap().center, None);
+
+    // Computed centers follow the edited vertices...
+    let moved = square.clone().with_vertex_moved(2, Point::new(9.0, 9.0));
+    let mut expected = vertices;
+
+    expected[2] = Point::new(9.0, 9.0);
+
+    assert_points(Point::new(2.5, 2.5), moved.center);
+    assert_polygons(
+        &RoundedPolygon::from_vertices(&expected, CornerRounding::UNROUNDED, &[], Point::splat(f32::MIN)),
+        &moved,
+    );
+    assert_points(Point::new(2.0 / 3.0, 1.0 / 3.0), square.clone().remove_vertex(3).center);
+    assert_points(
+        Point::new(0.8, 0.4),
+        square.insert_vertex(2, Point::new(2.0, 0.0), CornerRounding::UNROUNDED).center,
+    );
+
+    // ...while explicit ones are kept
+    let anchored = RoundedPolygon::from_vertices(&vertices, CornerRounding::UNROUNDED, &[], Point::splat(0.5));
+
+    assert_eq!(anchored.source().unwrap().center, Some(Point::splat(0.5)));
+    assert_points(Point::splat(0.5), anchored.with_vertex_moved(2, Point::new(9.0, 9.0)).center);
+}
+
+#[test]
+#[should_panic(expected = "Polygon has no construction source to edit")]
+fn editing_warped_test() {
+    let square = RoundedPolygon::from_vertices_count(4, 1.0, Some(CornerRounding::new(0.2)), &[]);
+    let bent = square.transformed_adaptive(polymorpher::warp::Bend::new(Point::zero(), 1.0), 0.001);
+
+    // Rebuilding from the vertices would silently undo the warp
+    let _ = bent.with_vertex_moved(0, Point::new(1.0, 0.0));
+}
+
+#[test]
+fn resized_test() {
+    let square = RoundedPolygon::from_vertices(
+        &[Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 1.0), Point::new(0.0, 1.0)],
+        CornerRounding::new(0.2),
+        &[],
+        Point::new(0.5, 0.5),
+    );
+    let resized = square.resized(Size::new(4.0, 2.0));
+    let aabb = resized.aabb(false);
+
+    assert_points(Point::new(-1.5, -0.5), aabb.min);
+    assert_points(Point::new(2.5, 1.5), aabb.max);
+    assert_eq!(resized.source().unwrap().roundings, [CornerRounding::new(0.2); 4]);
+
+    // Corners stay circular: the corner curve fits within the original radius
+    let corner = resized.features.iter().find(|feature| feature.is_corner()).unwrap();
+    let corner_size = corner.cubics.iter().fold(Size::zero(), |size, cubic| {
+        let aabb = cubic.aabb(false);
+
+        size.max(aabb.size())
+    });
+
+    assert!((corner_size.width - corner_size.height).abs() < EPSILON);
+}
+
+#[test]
+fn fit_into_test() {
+    let square = RoundedPolygon::from_vertices(
+        &[Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 1.0), Point::new(0.0, 1.0)],
+        CornerRounding::UNROUNDED,
+        &[],
+        Point::new(0.5, 0.5),
+    );
+    let rect = Aabb::new(Point::new(10.0, 10.0), Point::new(14.0, 12.0));
+    let fit = |mode, alignment| square.clone().fit_into(rect, Fit::new(mode, alignment).exact_bounds()).aabb(false);
+
+    let contain = fit(FitMode::Contain, Alignment::CENTER);
+
+    assert_points(Point::new(11.0, 10.0), contain.min);
+    assert_points(Point::new(13.0, 12.0), contain.max);
+
+    let contain = fit(FitMode::Contain, Alignment::TOP_RIGHT);
+
+    assert_points(Point::new(12.0, 10.0), contain.min);
+
+    let cover = fit(FitMode::Cover, Alignment::CENTER);
+
+    assert_points(Point::new(10.0, 9.0), cover.min);
+    assert_points(Point::new(14.0, 13.0), cover.max);
+
+    let fill = fit(FitMode::Fill, Alignment::CENTER);
+
+    assert_points(rect.min, fill.min);
+    assert_points(rect.max, fill.max);
+
+    let none = fit(FitMode::None, Alignment::BOTTOM_LEFT);
+
+    assert_points(Point::new(10.0, 11.0), none.min);
+    assert_points(Point::new(11.0, 12.0), none.max);
+}
+
+#[test]
+fn optical_centering_test() {
+    let triangle = RoundedPolygon::from_vertices(
+        &[Point::new(0.0, 0.0), Point::new(3.0, 0.0), Point::new(0.0, 3.0)],
+        CornerRounding::UNROUNDED,
+        &[],
+        Point::zero(),
+    );
+
+    assert_points(Point::new(1.0, 1.0), triangle.centroid().unwrap());
+
+    let rect = Aabb::new(Point::zero(), Point::new(6.0, 6.0));
+    let fitted = triangle.fit_into(rect, Fit::default().optical_centering());
+
+    assert_points(Point::new(3.0, 3.0), fitted.centroid().unwrap());
+}
+
+#[test]
+fn affine_transforms_test() {
+    let rotation = Matrix2::rotation_about(Angle::degrees(90.0), Point::new(1.0, 1.0));
+
+    assert_points(Point::new(1.0, 2.0), rotation.transform(Point::new(2.0, 1.0)));
+
+    let mirror = Matrix2::mirror(Point::new(1.0, 0.0), Vector::new(0.0, 1.0));
+
+    assert!(mirror.flips_winding());
+    assert!(!rotation.flips_winding());
+    assert_points(Point::new(-1.0, 5.0), mirror.transform(Point::new(3.0, 5.0)));
+
+    let skew = Matrix2::skew(Angle::degrees(45.0), Angle::zero());
+
+    assert_points(Point::new(3.0, 2.0), skew.transform(Point::new(1.0, 2.0)));
+
+    let scale = Matrix2::scale_about(2.0, 3.0, Point::new(1.0, 1.0));
+
+    assert_points(Point::new(3.0, 4.0), scale.transform(Point::new(2.0, 2.0)));
+}
+
+#[test]
+fn mirrored_test() {
+    // A shape with both convex and concave corners
+    let vertices = [
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(2.0, 1.0),
+        Point::new(1.0, 0.5),
+        Point::new(0.0, 2.0),
+    ];
+    let polygon = RoundedPolygon::from_vertices(&vertices, ROUNDING, &[], Point::new(1.0, 0.7));
+    let mirrored = polygon.mirrored(Point::zero(), Vector::new(0.0, 1.0));
+
+    // Mirroring keeps the winding direction, so the result is the same as building
+    // the polygon from mirrored vertices in reverse order
+    let mut expected_vertices = vertices.map(|vertex| Point::new(-vertex.x, vertex.y));
+
+    expected_vertices[1..].reverse();
+
+    let expected = RoundedPolygon::from_vertices(&expected_vertices, ROUNDING, &[], Point::new(-1.0, 0.7));
+
+    assert_polygons(&expected, &mirrored);
+    assert_eq!(expected.source(), mirrored.source());
+
+    // Rotations and scales keep the features in place
+    let rotated = expected.clone().rotated(Angle::degrees(360.0), Point::zero());
+
+    assert_polygons(&expected, &rotated);
+}
+
+#[test]
+fn from_cubics_test() {
+    let polygon = RoundedPolygon::rectangle().with_rounding(CornerRounding::new(0.25)).build();
+    let contours = polygon.as_path::<CubicsBuilder>(false, true);
+
+    assert_eq!(contours.len(), 1);
+
+    let imported = RoundedPolygon::from_cubics(&contours[0]);
+    let corners = imported.features.iter().filter(|feature| feature.is_corner_and(|convex| convex)).count();
+    let edges = imported.features.iter().filter(|feature| !feature.is_corner()).count();
+
+    assert_eq!(corners, 4);
+    assert_eq!(edges, 4);
+    assert_points(polygon.aabb(false).min, imported.aabb(false).min);
+    assert_points(polygon.aabb(false).max, imported.aabb(false).max);
+
+    // An open outline winding the other way around, with one concave vertex
+    let mut builder = CubicsBuilder::new();
+
+    builder.move_to(Point::new(0.0, 0.0));
+
+    for point in [(0.0, 2.0), (2.0, 2.0), (1.0, 1.0), (2.0, 0.0)] {
+        builder.line_to(Point::from(point));
+    }
+
+    let polygon = RoundedPolygon::from_cubics(&builder.build()[0]);
+    let convex = polygon.features.iter().filter(|feature| feature.is_corner_and(|convex| convex)).count();
+    let concave = polygon.features.iter().filter(|feature| feature.is_corner_and(|convex| !convex)).count();
+
+    assert_eq!((convex, concave), (4, 1));
+    assert_eq!(polygon.features.iter().filter(|feature| !feature.is_corner()).count(), 5);
+    assert_points(polygon.cubics[0].anchor0(), polygon.cubics.last().unwrap().anchor1());
+
+    // A circle made of conics is split into smooth corners of a sixth of it
+    let mut builder = CubicsBuilder::new();
+    let height = 3.0f32.sqrt();
+
+    // Thirds of the circle, which are split before being approximated
+    builder.move_to(Point::new(1.0, 0.0));
+    builder.conic_to(Point::new(1.0, height), Point::new(-0.5, height / 2.0), 0.5);
+    builder.conic_to(Point::new(-2.0, 0.0), Point::new(-0.5, -height / 2.0), 0.5);
+    builder.conic_to(Point::new(1.0, -height), Point::new(1.0, 0.0), 0.5);
+    builder.close();
+
+    let contours = builder.build();
+    let polygon = RoundedPolygon::from_cubics(&contours[0]);
+
+    assert_eq!(polygon.features.len(), 6);
+    assert!(polygon.features.iter().all(|feature| feature.is_corner_and(|convex| convex)));
+
+    for cubic in &polygon.cubics {
+        for t in [0.0, 0.25, 0.5, 0.75] {
+            assert_approx_eq!(f32, cubic.point_on_curve(t).to_vector().length(), 1.0, epsilon = 1e-3);
+        }
+    }
+}
+
+#[test]
+fn from_degenerate_cubics_test() {
+    let point = Point::new(1.0, 2.0);
+    let mut builder = CubicsBuilder::new();
+
+    builder.move_to(point);
+    builder.line_to(point);
+    builder.cubic_to(point, point, point);
+
+    let contours = builder.build();
+    let polygon = RoundedPolygon::from_cubics(&contours[0]);
+
+    assert!(polygon.features.is_empty());
+    assert_points(point, polygon.center);
+    assert!(polygon.cubics.iter().all(Cubic::zero_length));
+}
+
+#[cfg(feature = "kurbo")]
+#[test]
+fn from_kurbo_path_test() {
+    use polymorpher::path::ToCubics;
+
+    let mut path = kurbo::BezPath::new();
+
+    path.move_to((0.0, 0.0));
+    path.line_to((2.0, 0.0));
+    path.quad_to((2.0, 2.0), (0.0, 2.0));
+    path.close_path();
+    path.move_to((5.0, 5.0));
+    path.line_to((6.0, 5.0));
+    path.line_to((6.0, 6.0));
+
+    let contours = path.to_cubics();
+
+    assert_eq!(contours.len(), 2);
+    assert_eq!(contours[0].len(), 3);
+    assert_points(Point::new(2.0, 4.0 / 3.0), contours[0][1].control0());
+
+    let polygons = RoundedPolygon::from_path(&path);
+
+    assert_eq!(polygons.len(), 2);
+    assert_eq!(polygons[1].features.iter().filter(|feature| feature.is_corner()).count(), 3);
+}
+
+#[cfg(feature = "lyon")]
+#[test]
+fn from_lyon_path_test() {
+    use lyon_tessellation::{math::point, path::Path};
+    use polymorpher::path::ToCubics;
+
+    let mut builder = Path::builder();
+
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(2.0, 0.0));
+    builder.quadratic_bezier_to(point(2.0, 2.0), point(0.0, 2.0));
+    builder.end(true);
+    builder.begin(point(5.0, 5.0));
+    builder.line_to(point(6.0, 5.0));
+    builder.line_to(point(6.0, 6.0));
+    builder.end(false);
+
+    let path = builder.build();
+    let contours = path.to_cubics();
+
+    assert_eq!(contours.len(), 2);
+    assert_eq!(contours[0].len(), 3);
+    assert_points(Point::new(2.0, 4.0 / 3.0), contours[0][1].control0());
+
+    let polygons = RoundedPolygon::from_path(&path);
+
+    assert_eq!(polygons.len(), 2);
+    assert_eq!(polygons[1].features.iter().filter(|feature| feature.is_corner()).count(), 3);
+}
+
+#[cfg(feature = "tiny-skia")]
+#[test]
+fn from_tiny_skia_path_test() {
+    use polymorpher::path::ToCubics;
+    use tiny_skia_path::PathBuilder;
+
+    let mut builder = PathBuilder::new();
+
+    builder.move_to(0.0, 0.0);
+    builder.line_to(2.0, 0.0);
+    builder.quad_to(2.0, 2.0, 0.0, 2.0);
+    builder.close();
+    builder.move_to(5.0, 5.0);
+    builder.line_to(6.0, 5.0);
+    builder.line_to(6.0, 6.0);
+
+    let path = builder.finish().unwrap();
+    let contours = path.to_cubics();
+
+    assert_eq!(contours.len(), 2);
+    assert_eq!(contours[0].len(), 3);
+    assert_points(Point::new(2.0, 4.0 / 3.0), contours[0][1].control0());
+
+    let polygons = RoundedPolygon::from_path(&path);
+
+    assert_eq!(polygons.len(), 2);
+    assert_eq!(polygons[1].features.iter().filter(|feature| feature.is_corner()).count(), 3);
+}
+
+#[cfg(feature = "skia")]
+#[test]
+fn from_skia_path_test() {
+    use core::f32::consts::FRAC_1_SQRT_2;
+
+    use polymorpher::path::ToCubics;
+
+    // A unit circle made of conic quarter arcs
+    let mut path = skia_safe::Path::new();
+
+    path.move_to((1.0, 0.0));
+    path.conic_to((1.0, 1.0), (0.0, 1.0), FRAC_1_SQRT_2);
+    path.conic_to((-1.0, 1.0), (-1.0, 0.0), FRAC_1_SQRT_2);
+    path.conic_to((-1.0, -1.0), (0.0, -1.0), FRAC_1_SQRT_2);
+    path.conic_to((1.0, -1.0), (1.0, 0.0), FRAC_1_SQRT_2);
+    path.close();
+
+    let contours = path.to_cubics();
+
+    assert_eq!(contours.len(), 1);
+    assert_eq!(contours[0].len(), 4);
+
+    for cubic in &contours[0] {
+        assert_approx_eq!(f32, 1.0, cubic.point_on_curve(0.5).to_vector().length(), epsilon = 1e-3);
+    }
+
+    assert_eq!(RoundedPolygon::from_path(&path).len(), 1);
+}
+
+#[cfg(feature = "geo")]
+#[test]
+fn geo_polygon_test() {
+    use geo_types::polygon;
+    use polymorpher::VertexRounding;
+
+    let square = [Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 1.0), Point::new(0.0, 1.0)];
+    let rounded = RoundedPolygon::from_vertices(&square, CornerRounding::new(0.2), &[], Point::splat(0.5));
+    let polygon = rounded.to_geo_polygon(1e-4);
+
+    assert!(polygon.exterior().is_closed());
+    assert!(polygon.interiors().is_empty());
+
+    let detected = RoundedPolygon::from_geo_polygon(&polygon, VertexRounding::Detected);
+    let source = detected.source().unwrap();
+
+    assert_eq!(source.vertices.len(), 4);
+    assert_points(Point::splat(0.5), source.center());
+
+    for (vertex, rounding) in source.vertices.iter().zip(&source.roundings) {
+        assert!(square.iter().any(|corner| corner.distance_to(*vertex) < 1e-3));
+        assert_approx_eq!(f32, 0.2, rounding.radius, epsilon = 2e-3);
+    }
+
+    // Clockwise, with a repeated and a collinear point
+    let triangle = polygon![(x: 0.0, y: 0.0), (x: 0.0, y: 2.0), (x: 1.0, y: 1.0), (x: 2.0, y: 0.0), (x: 2.0, y: 0.0)];
+    let uniform = RoundedPolygon::from_geo_polygon(&triangle, VertexRounding::Uniform(CornerRounding::new(0.1)));
+    let source = uniform.source().unwrap();
+
+    assert_eq!(source.vertices, [Point::new(2.0, 0.0), Point::new(0.0, 2.0), Point::new(0.0, 0.0)]);
+    assert_eq!(source.roundings, [CornerRounding::new(0.1); 3]);
+
+    let circle = RoundedPolygon::circle().with_radius(1.0).build();
+    let multi = RoundedPolygon::to_geo_multi_polygon([&circle, &uniform], 1e-3);
+    let polygons = RoundedPolygon::from_geo_multi_polygon(&multi, VertexRounding::Detected);
+
+    assert_eq!(multi.0.len(), 2);
+    assert_eq!(polygons.len(), 2);
+
+    for cubic in &polygons[0].cubics {
+        assert_approx_eq!(f32, cubic.point_on_curve(0.5).to_vector().length(), 1.0, epsilon = 1e-2);
+    }
+
+    // Wavy outlines keep their convex and concave arcs apart
+    for shape in [
+        polymorpher::shapes::very_sunny(),
+        polymorpher::shapes::cookie4(),
+        polymorpher::shapes::cookie12(),
+        polymorpher::shapes::soft_burst(),
+    ] {
+        let detected = RoundedPolygon::from_geo_polygon(&shape.to_geo_polygon(1e-3), VertexRounding::Detected);
+        let (expected, actual) = (shape.aabb(false), detected.aabb(false));
+
+        assert!((expected.min - actual.min).length() < 1e-2 && (expected.max - actual.max).length() < 1e-2);
+
+        let outline = shape.flattened(1e-4);
+
+        for point in detected.flattened(1e-3) {
+            assert!(outline.iter().any(|other| other.distance_to(point) < 5e-2));
+        }
+    }
+}
+
+#[cfg(feature = "ttf-parser")]
+#[test]
+fn outline_builder_test() {
+    use ttf_parser::OutlineBuilder;
+
+    // A TrueType-like outline of a letter `O`, clockwise with quadratic curves
+    let mut builder = CubicsBuilder::new();
+
+    let outline: &mut dyn OutlineBuilder = &mut builder;
+
+    for radius in [2.0, 1.0] {
+        let sign = if radius > 1.0 { 1.0 } else { -1.0 };
+
+        outline.move_to(radius, 0.0);
+        outline.quad_to(radius, -radius * sign, 0.0, -radius * sign);
+        outline.quad_to(-radius, -radius * sign, -radius, 0.0);
+        outline.quad_to(-radius, radius * sign, 0.0, radius * sign);
+        outline.quad_to(radius, radius * sign, radius, 0.0);
+        outline.close();
+    }
+
+    let contours = PathBuilder::build(builder);
+
+    assert_eq!(contours.len(), 2);
+    assert_eq!(contours[0].len(), 4);
+    assert_points(Point::new(2.0, -4.0 / 3.0), contours[0][0].control0());
+
+    let polygons = contours.iter().map(|contour| RoundedPolygon::from_cubics(contour)).collect::<Vec<_>>();
+
+    for (polygon, radius) in polygons.iter().zip([2.0, 1.0]) {
+        assert!(polygon.features.iter().all(|feature| feature.is_corner_and(|convex| convex)));
+        assert_approx_eq!(f32, radius, polygon.cubics[0].anchor0().to_vector().length(), epsilon = EPSILON);
+    }
+
+    // The inner contour is reversed to the winding of polygons in this crate
+    let area = |polygon: &RoundedPolygon| {
+        polygon
+            .cubics
+            .iter()
+            .map(|cubic| cubic.anchor0().to_vector().cross(cubic.anchor1().to_vector()))
+            .sum::<f32>()
+    };
+
+    assert!(polygons.iter().all(|polygon| area(polygon) > 0.0));
+    assert_eq!(polygons[1].features.len(), 4);
+
+    // Smooth outlines are split into several corners, so they morph into other
+    // shapes starting from the imported outline
+    let morph = polymorpher::Morph::new(polygons[0].clone(), polymorpher::shapes::cookie7());
+    let outline = polygons[0].flattened(1e-4);
+    let distance = |point: Point| {
+        (0..outline.len())
+            .map(|i| {
+                let (start, end) = (outline[i], outline[(i + 1) % outline.len()]);
+                let along = ((point - start).dot(end - start) / (end - start).square_length()).clamp(0.0, 1.0);
+
+                point.distance_to(start.lerp(end, along))
+            })
+            .fold(f32::MAX, f32::min)
+    };
+
+    for cubic in morph.as_cubics(0.0) {
+        assert!(distance(cubic.anchor0()) < 1e-3);
+    }
+}
+
+#[cfg(feature = "ttf-parser")]
+#[test]
+fn glyph_test() {
+    use polymorpher::path::glyph_cubics;
+    use ttf_parser::{Face, GlyphId};
+
+    // The demo font of `ttf-parser`, with an `A` made of an outer contour and a
+    // triangular counter
+    let face = Face::parse(include_bytes!("fonts/demo.ttf"), 0).unwrap();
+    let glyph = face.glyph_index('A').unwrap();
+    let contours = glyph_cubics(&face, glyph).unwrap();
+
+    assert_eq!(contours.len(), 2);
+    assert_eq!(contours[0].len(), 3);
+    assert_eq!(contours[1].len(), 8);
+
+    // The y axis points down, so the apex of the letter is above the baseline
+    assert_eq!(contours[0][2].anchor0(), Point::new(270.0, -587.0));
+    assert_eq!(contours[1][1].anchor0(), Point::new(224.0, -656.0));
+    assert!(contours.iter().flatten().all(|cubic| cubic.anchor0().y <= 0.0));
+
+    let polygons = RoundedPolygon::from_glyph(&face, glyph);
+
+    assert_eq!(polygons.len(), 2);
+    assert_eq!(polygons[0].aabb(false), Aabb::new(Point::new(173.0, -587.0), Point::new(369.0, -267.0)));
+    assert_eq!(polygons[1].aabb(false), Aabb::new(Point::new(6.0, -656.0), Point::new(541.0, 0.0)));
+    assert_eq!(polygons[1].features.iter().filter(|feature| feature.is_corner_and(|_| true)).count(), 8);
+
+    // Glyphs without an outline give no contours and no polygons
+    let missing = GlyphId(face.number_of_glyphs());
+
+    assert!(glyph_cubics(&face, missing).is_none());
+    assert!(RoundedPolygon::from_glyph(&face, missing).is_empty());
+}
+
+#[test]
+fn flattened_test() {
+    let circle = RoundedPolygon::circle().with_radius(1.0).build();
+
+    for tolerance in [0.1, 0.01, 0.001] {
+        let points = circle.flattened(tolerance);
+
+        assert_ne!(points.first(), points.last());
+
+        for (i, point) in points.iter().enumerate() {
+            let middle = point.lerp(points[(i + 1) % points.len()], 0.5);
+
+            assert!((point.to_vector().length() - 1.0).abs() < 1e-3);
+            assert!(1.0 - middle.to_vector().length() <= tolerance);
+        }
+    }
+
+    assert!(circle.flattened(0.001).len() > circle.flattened(0.1).len());
+}
+
+#[test]
+fn shapes_catalog_test() {
+    let shapes = polymorpher::shapes::all();
+
+    assert_eq!(shapes.len(), 35);
+
+    for (name, shape) in shapes {
+        let aabb = shape.aabb(false);
+
+        assert!(aabb.min.x >= -EPSILON && aabb.min.y >= -EPSILON, "{name} is not normalized");
+        assert!(aabb.max.x <= 1.0 + EPSILON && aabb.max.y <= 1.0 + EPSILON, "{name} is not normalized");
+    }
+}
@@ -0,0 +1,104 @@
+use core::f32;
+
+use float_cmp::assert_approx_eq;
+use polymorpher::{
+    Cubic, RoundedPolygon,
+    geometry::{Aabb, Angle, Point, PointTransformer},
+    warp::{Bend, Bulge, Envelope, Twist, Wave},
+};
+
+const EPSILON: f32 = 1e-4;
+
+fn assert_points(expected: Point, actual: Point) {
+    assert_approx_eq!(f32, expected.x, actual.x, epsilon = EPSILON);
+    assert_approx_eq!(f32, expected.y, actual.y, epsilon = EPSILON);
+}
+
+#[test]
+fn bend_test() {
+    let bend = Bend::new(Point::new(1.0, 1.0), 1.0);
+
+    assert_points(Point::new(1.0, 1.0), bend.transform(Point::new(1.0, 1.0)));
+    assert_points(Point::new(2.0, 2.0), bend.transform(Point::new(1.0 + f32::consts::FRAC_PI_2, 1.0)));
+    // Points closer to the center of the bend end up on a smaller arc
+    assert_points(Point::new(1.5, 2.0), bend.transform(Point::new(1.0 + f32::consts::FRAC_PI_2, 1.5)));
+    assert_points(Point::new(3.0, 4.0), Bend::new(Point::zero(), 0.0).transform(Point::new(3.0, 4.0)));
+}
+
+#[test]
+fn twist_test() {
+    let twist = Twist::new(Point::zero(), Angle::degrees(180.0), 2.0);
+
+    assert_points(Point::new(-1.0, 0.0), twist.transform(Point::new(0.0, 1.0)));
+    assert_points(Point::zero(), twist.transform(Point::zero()));
+    assert_points(Point::new(3.0, 0.0), twist.transform(Point::new(3.0, 0.0)));
+}
+
+#[test]
+fn bulge_test() {
+    let bulge = Bulge::new(Point::zero(), 2.0, 0.5);
+    let pinch = Bulge::new(Point::zero(), 2.0, -0.5);
+
+    assert_points(Point::zero(), bulge.transform(Point::zero()));
+    assert_points(Point::new(2.0, 0.0), bulge.transform(Point::new(2.0, 0.0)));
+    assert!(bulge.transform(Point::new(1.0, 0.0)).x > 1.0);
+    assert!(pinch.transform(Point::new(1.0, 0.0)).x < 1.0);
+}
+
+#[test]
+fn wave_test() {
+    let wave = Wave::new(0.5, 4.0, 0.0);
+
+    assert_points(Point::new(1.0, 1.5), wave.transform(Point::new(1.0, 1.0)));
+    assert_points(Point::new(2.0, 1.0), wave.transform(Point::new(2.0, 1.0)));
+}
+
+#[test]
+fn envelope_test() {
+    let source = Aabb::new(Point::zero(), Point::new(2.0, 2.0));
+    let corners = [Point::new(0.0, 0.0), Point::new(4.0, 1.0), Point::new(3.0, 3.0), Point::new(-1.0, 2.0)];
+
+    for envelope in [Envelope::bilinear(source, corners), Envelope::perspective(source, corners)] {
+        for (corner, expected) in [source.min, Point::new(2.0, 0.0), source.max, Point::new(0.0, 2.0)].into_iter().zip(corners) {
+            assert_points(expected, envelope.transform(corner));
+        }
+    }
+
+    // Bilinear interpolation maps the center to the average of the corners...
+    assert_points(Point::new(1.5, 1.5), Envelope::bilinear(source, corners).transform(Point::new(1.0, 1.0)));
+
+    // ...while the perspective keeps straight lines straight
+    let perspective = Envelope::perspective(source, corners);
+    let diagonal = perspective.transform(Point::new(0.5, 0.5)) - corners[0];
+    let direction = corners[2] - corners[0];
+
+    assert_approx_eq!(f32, diagonal.cross(direction), 0.0, epsilon = EPSILON);
+}
+
+#[test]
+fn transformed_adaptive_test() {
+    let bend = Bend::new(Point::zero(), 1.0);
+    let line = Cubic::straight_line(Point::zero(), Point::new(f32::consts::PI, 0.0));
+    let tolerance = 1e-3;
+
+    assert_eq!(line.transformed_adaptive(&bend, 10.0), [line.transformed(&bend)]);
+
+    let cubics = line.transformed_adaptive(&bend, tolerance);
+
+    assert!(cubics.len() > 1);
+
+    // The result follows the bend within tolerance
+    for cubic in &cubics {
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let distance = (cubic.point_on_curve(t) - Point::new(0.0, 1.0)).length();
+
+            assert!((distance - 1.0).abs() < tolerance * 2.0);
+        }
+    }
+
+    let polygon = RoundedPolygon::rectangle().build();
+    let warped = polygon.clone().transformed_adaptive(bend, tolerance);
+
+    assert!(warped.cubics.len() > polygon.cubics.len());
+    assert_eq!(warped.features.len(), polygon.features.len());
+}